@@ -1,68 +1,73 @@
-use std::fmt::format;
-
-use crate::convert::go_body;
-use crate::convert::go_print;
-use crate::convert::go_utils;
-use crate::core::debugging;
-use crate::core::enums;
-use crate::core::errors;
-use crate::core::printing;
-use crate::core::structs;
-use crate::format::ast_expressions;
-use crate::format::ast_functions;
-use crate::format::ast_utils;
-use enums::ASTNodeType;
-use enums::TokenType;
-use errors::AstError;
-use errors::ConvertError;
-use structs::ASTData;
-use structs::ASTNode;
-use structs::ConvertData;
-use structs::StringBuilder;
-use structs::Token;
-
-const NEW_LINE: bool = true;
-const TABS: bool = true;
-
-pub fn process_body(convert_data: &mut ConvertData, node: ASTNode) {
-    
-    convert_data.error_function = "processBody".to_string();
-
-    //NodeType Type
-    //Children body nodes
-
-    let child_count: usize = node.children.len();
-    if child_count == 0 {
-        return;
-    }
-    for i in 0..child_count {
-        let child:ASTNode = node.children[i].clone();
-        process_function_body_node(convert_data, child, NEW_LINE, TABS);
-    }
-}
-
-fn process_function_body_node(convert_data:&mut ConvertData, node:ASTNode, add_new_line:bool, add_tabs:bool) {
-    unsafe {
-        convert_data.error_function = "processFunctionBodyNode".to_string();
-        let node_type: i32 = node.node_type;
-
-        if node_type == ASTNodeType::Invalid {
-            convert_data.error_token = (*node.token).clone();
-            convert_data.error_code = ConvertError::InvalidNodeType;
-            return;
-        }
-
-        match node_type {
-            ASTNodeType::Println => go_print::process_print(convert_data, node, true),
-            _ => {
-                convert_data.error_token = (*node.token).clone();
-                convert_data.error_detail = format!(
-                    "{} not implemented yet",
-                    enums::astnodetype_to_string(node.node_type)
-                );
-                convert_data.error_code = ConvertError::UnimplementedNodeType;
-                return;
-            }
-        }
-    }
-}
+use crate::convert::go_control_flow;
+use crate::convert::go_declarations;
+use crate::convert::go_functions;
+use crate::convert::go_print;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use enums::ASTNodeType;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+
+const NEW_LINE: bool = true;
+const TABS: bool = true;
+
+pub fn process_body(convert_data: &mut ConvertData, node: ASTNode) {
+    
+    convert_data.error_function = "processBody".to_string();
+
+    //NodeType Type
+    //Children body nodes
+
+    let child_count: usize = node.children.len();
+    if child_count == 0 {
+        return;
+    }
+    for i in 0..child_count {
+        let child:ASTNode = node.children[i].clone();
+        process_function_body_node(convert_data, child, NEW_LINE, TABS);
+        if convert_data.is_error() {
+            return;
+        }
+    }
+}
+
+fn process_function_body_node(convert_data:&mut ConvertData, node:ASTNode, _add_new_line:bool, _add_tabs:bool) {
+    unsafe {
+        convert_data.error_function = "processFunctionBodyNode".to_string();
+        let node_type: i32 = node.node_type;
+
+        if node_type == ASTNodeType::Invalid {
+            convert_data.error_token = (*node.token).clone();
+            convert_data.error_code = ConvertError::InvalidNodeType;
+            return;
+        }
+
+        match node_type {
+            ASTNodeType::Println => go_print::process_print(convert_data, node, true),
+            ASTNodeType::Print => go_print::process_print(convert_data, node, false),
+            ASTNodeType::Declaration => go_declarations::process_declaration(convert_data, node),
+            ASTNodeType::MultipleDeclaration => go_declarations::process_multiple_declaration(convert_data, node),
+            ASTNodeType::Assignment => go_declarations::process_assignment(convert_data, node),
+            ASTNodeType::Return => go_functions::process_return(convert_data, node),
+            ASTNodeType::FunctionCall => go_functions::process_function_call(convert_data, node),
+            ASTNodeType::Spawn => go_functions::process_spawn(convert_data, node),
+            ASTNodeType::IfStatement => go_control_flow::process_if(convert_data, node),
+            ASTNodeType::WhileLoop => go_control_flow::process_while(convert_data, node),
+            ASTNodeType::ForLoop => go_control_flow::process_for(convert_data, node),
+            ASTNodeType::DoWhileLoop => go_control_flow::process_do_while(convert_data, node),
+            ASTNodeType::Label => go_control_flow::process_label(convert_data, node),
+            ASTNodeType::Break | ASTNodeType::Continue => go_control_flow::process_loop_exit(convert_data, node),
+            _ => {
+                convert_data.error_token = (*node.token).clone();
+                convert_data.error_detail = format!(
+                    "{} not implemented yet",
+                    enums::astnodetype_to_string(node.node_type)
+                );
+                convert_data.error_code = ConvertError::UnimplementedNodeType;
+                return;
+            }
+        }
+    }
+}
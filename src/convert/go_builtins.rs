@@ -0,0 +1,305 @@
+use crate::convert::go_helpers;
+use crate::convert::go_imports;
+use crate::convert::go_print_expressions;
+use crate::convert::go_utils;
+use crate::core::builtins;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::Token;
+
+pub fn print_builtin_constant(convert_data:&mut ConvertData, name:&str) {
+    convert_data.add_import(go_imports::MATH);
+    match name {
+        builtins::PI => convert_data.code_builder.append("math.Pi"),
+        builtins::E => convert_data.code_builder.append("math.E"),
+        _ => {}
+    }
+}
+
+pub fn print_builtin_call(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    convert_data.error_function = "printBuiltinCall".to_string();
+
+    //Node_type: FunctionCall
+    //Token:     builtin name
+    //Children:  arguments
+
+    let name_token:Token;
+    unsafe {
+        name_token = (*node.token).clone();
+    }
+    let name:&str = &name_token.text;
+
+    let argument_types:Vec<String> = builtins::get_argument_types(node, &convert_data.symbol_table);
+
+    if builtins::is_math_function(name) == false {
+        print_fixed_signature_call(convert_data, node);
+        return;
+    }
+
+    let shared_type:String = builtins::get_shared_argument_type(&argument_types);
+    let result_type:String = builtins::get_builtin_call_type(name, &argument_types);
+
+    if builtins::is_float_math_function(name) || type_utils::is_float_type_text(&shared_type) {
+        print_float_math_call(convert_data, node, &shared_type, &result_type);
+        return;
+    }
+    print_integer_math_call(convert_data, node, &shared_type);
+}
+
+fn get_go_math_function(name:&str) -> &'static str {
+    match name {
+        builtins::SQRT => return "math.Sqrt",
+        builtins::POW => return "math.Pow",
+        builtins::FLOOR => return "math.Floor",
+        builtins::CEIL => return "math.Ceil",
+        builtins::SIN => return "math.Sin",
+        builtins::COS => return "math.Cos",
+        builtins::ABS => return "math.Abs",
+        builtins::MIN => return "math.Min",
+        builtins::MAX => return "math.Max",
+        _ => return "",
+    }
+}
+
+// math.Sqrt(float64(x)), wrapped in float32(...) when an f32 goes in
+fn print_float_math_call(convert_data:&mut ConvertData, node:&ASTNode, shared_type:&str, result_type:&str) {
+    unsafe {
+        let name:String = (*node.token).text.clone();
+        convert_data.add_import(go_imports::MATH);
+
+        //untyped literals and float64 already fit
+        let needs_conversion:bool = shared_type != "f64" && type_utils::is_literal_type_text(shared_type) == false;
+        let is_f32:bool = result_type == "f32";
+
+        if is_f32 {
+            convert_data.code_builder.append("float32(");
+        }
+        convert_data.code_builder.append_fmt(format!("{}(", get_go_math_function(&name)));
+
+        let argument_count:usize = node.children.len();
+        for i in 0..argument_count {
+            if i != 0 {
+                convert_data.code_builder.append(", ");
+            }
+            if needs_conversion {
+                convert_data.code_builder.append("float64(");
+            }
+            go_print_expressions::print_expression(convert_data, &node.children[i]);
+            if convert_data.is_error() {
+                return;
+            }
+            if needs_conversion {
+                convert_data.code_builder.append(")");
+            }
+        }
+        convert_data.code_builder.append(")");
+
+        if is_f32 {
+            convert_data.code_builder.append(")");
+        }
+    }
+}
+
+// Go's math package is float64 only, integers use a generic helper instead
+fn print_integer_math_call(convert_data:&mut ConvertData, node:&ASTNode, shared_type:&str) {
+    unsafe {
+        let name:String = (*node.token).text.clone();
+        let helper_name:&str = match name.as_str() {
+            builtins::ABS => go_helpers::ABS_HELPER,
+            builtins::MIN => go_helpers::MIN_HELPER,
+            _ => go_helpers::MAX_HELPER,
+        };
+        go_helpers::use_helper(convert_data, helper_name);
+
+        //only literals, Go would pick int so they are given the default i32
+        let mut literal_go_type:String = String::new();
+        if type_utils::is_literal_type_text(shared_type) {
+            literal_go_type = go_utils::type_text_to_go_type(&builtins::get_default_literal_type(shared_type));
+        }
+
+        convert_data.code_builder.append_fmt(format!("{}(", helper_name));
+
+        let argument_count:usize = node.children.len();
+        for i in 0..argument_count {
+            if i != 0 {
+                convert_data.code_builder.append(", ");
+            }
+            if literal_go_type.is_empty() == false {
+                convert_data.code_builder.append_fmt(format!("{}(", literal_go_type));
+            }
+            go_print_expressions::print_expression(convert_data, &node.children[i]);
+            if convert_data.is_error() {
+                return;
+            }
+            if literal_go_type.is_empty() == false {
+                convert_data.code_builder.append(")");
+            }
+        }
+        convert_data.code_builder.append(")");
+    }
+}
+
+/// Builtins written as a Go helper function taking the same arguments
+fn get_builtin_helper(name:&str) -> &'static str {
+    match name {
+        builtins::PARSE_I32 => return go_helpers::PARSE_I32_HELPER,
+        builtins::READLN => return go_helpers::READLN_HELPER,
+        builtins::READ_I32 => return go_helpers::READ_I32_HELPER,
+        builtins::READ_F64 => return go_helpers::READ_F64_HELPER,
+        builtins::READ_FILE => return go_helpers::READ_FILE_HELPER,
+        builtins::READ_LINES => return go_helpers::READ_LINES_HELPER,
+        builtins::WRITE_FILE => return go_helpers::WRITE_FILE_HELPER,
+        builtins::APPEND_FILE => return go_helpers::APPEND_FILE_HELPER,
+        _ => return "",
+    }
+}
+
+fn print_arguments(convert_data:&mut ConvertData, node:&ASTNode) {
+    let argument_count:usize = node.children.len();
+    for i in 0..argument_count {
+        if i != 0 {
+            convert_data.code_builder.append(", ");
+        }
+        go_print_expressions::print_expression(convert_data, &node.children[i]);
+        if convert_data.is_error() {
+            return;
+        }
+    }
+}
+
+// add(wg, 2) becomes wg.Add(int(2)), the value is a *sync.WaitGroup or *sync.Mutex
+fn print_sync_call(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let name:String = (*node.token).text.clone();
+        let go_method:&str = match name.as_str() {
+            builtins::ADD => "Add",
+            builtins::DONE => "Done",
+            builtins::WAIT => "Wait",
+            builtins::LOCK => "Lock",
+            _ => "Unlock",
+        };
+
+        go_print_expressions::print_indexed_value(convert_data, &node.children[0]);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append_fmt(format!(".{}(", go_method));
+        if node.children.len() > 1 {
+            convert_data.code_builder.append("int(");
+            go_print_expressions::print_expression(convert_data, &node.children[1]);
+            convert_data.code_builder.append(")");
+        }
+        convert_data.code_builder.append(")");
+    }
+}
+
+// panic("main.src:4:5: assertion failed: " + message), the location is the call's in our source
+fn print_failure_call(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        let location:String = convert_data.get_source_location(&name_token);
+
+        if name_token.text == builtins::PANIC {
+            convert_data.code_builder.append_fmt(format!("panic({:?} + ", format!("{}: ", location)));
+            go_print_expressions::print_expression(convert_data, &node.children[0]);
+            convert_data.code_builder.append(")");
+            return;
+        }
+
+        //a helper so assert still works inside an expression
+        go_helpers::use_helper(convert_data, go_helpers::ASSERT_HELPER);
+        convert_data.code_builder.append_fmt(format!("{}(", go_helpers::ASSERT_HELPER));
+        go_print_expressions::print_expression(convert_data, &node.children[0]);
+        if convert_data.is_error() {
+            return;
+        }
+        if node.children.len() == 1 {
+            convert_data.code_builder.append_fmt(format!(", {:?})", format!("{}: assertion failed", location)));
+            return;
+        }
+        convert_data.code_builder.append_fmt(format!(", {:?} + ", format!("{}: assertion failed: ", location)));
+        go_print_expressions::print_expression(convert_data, &node.children[1]);
+        convert_data.code_builder.append(")");
+    }
+}
+
+fn print_fixed_signature_call(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let name:String = (*node.token).text.clone();
+
+        match name.as_str() {
+            builtins::LEN => {
+                //Go's len is an int, the language's sizes are i32
+                convert_data.code_builder.append("int32(len(");
+                print_arguments(convert_data, node);
+                convert_data.code_builder.append("))");
+            }
+            builtins::SUBSTRING => {
+                //s[start:end]
+                go_print_expressions::print_indexed_value(convert_data, &node.children[0]);
+                if convert_data.is_error() {
+                    return;
+                }
+                convert_data.code_builder.append("[");
+                go_print_expressions::print_expression(convert_data, &node.children[1]);
+                convert_data.code_builder.append(":");
+                go_print_expressions::print_expression(convert_data, &node.children[2]);
+                convert_data.code_builder.append("]");
+            }
+            builtins::CONTAINS | builtins::SPLIT | builtins::TRIM => {
+                convert_data.add_import(go_imports::STRINGS);
+                let go_function:&str = match name.as_str() {
+                    builtins::CONTAINS => "strings.Contains",
+                    builtins::SPLIT => "strings.Split",
+                    _ => "strings.TrimSpace",
+                };
+                convert_data.code_builder.append_fmt(format!("{}(", go_function));
+                print_arguments(convert_data, node);
+                convert_data.code_builder.append(")");
+            }
+            builtins::SEND => {
+                //c <- v
+                go_print_expressions::print_indexed_value(convert_data, &node.children[0]);
+                if convert_data.is_error() {
+                    return;
+                }
+                convert_data.code_builder.append(" <- ");
+                go_print_expressions::print_expression(convert_data, &node.children[1]);
+            }
+            builtins::RECV => {
+                convert_data.code_builder.append("<-");
+                go_print_expressions::print_indexed_value(convert_data, &node.children[0]);
+            }
+            builtins::ADD | builtins::DONE | builtins::WAIT | builtins::LOCK | builtins::UNLOCK => {
+                print_sync_call(convert_data, node);
+            }
+            builtins::ASSERT | builtins::PANIC => {
+                print_failure_call(convert_data, node);
+            }
+            builtins::TO_STRING => {
+                convert_data.add_import(go_imports::STRCONV);
+                convert_data.code_builder.append("strconv.Itoa(int(");
+                print_arguments(convert_data, node);
+                convert_data.code_builder.append("))");
+            }
+            _ => {
+                let helper_name:&str = get_builtin_helper(&name);
+                if helper_name.is_empty() == false {
+                    go_helpers::use_helper(convert_data, helper_name);
+                    convert_data.code_builder.append_fmt(format!("{}(", helper_name));
+                    print_arguments(convert_data, node);
+                    convert_data.code_builder.append(")");
+                    return;
+                }
+                convert_data.error_token = (*node.token).clone();
+                convert_data.error_detail = format!("builtin '{}' has no Go version yet", name);
+                convert_data.error_code = ConvertError::UnimplementedNodeType;
+            }
+        }
+    }
+}
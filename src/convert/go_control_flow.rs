@@ -0,0 +1,590 @@
+use crate::convert::go_body;
+use crate::convert::go_declarations;
+use crate::convert::go_print_expressions;
+use crate::convert::go_ternary;
+use crate::convert::go_utils;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::LoopLabel;
+use structs::Token;
+
+// { body }, its declarations end with it
+fn write_block(convert_data:&mut ConvertData, body_node_ptr:*mut ASTNode) {
+    if body_node_ptr.is_null() {
+        convert_data.error_detail = "block has no body".to_string();
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+    convert_data.code_builder.append(" {\n");
+    convert_data.symbol_table.enter_scope();
+    convert_data.increment_index_count();
+    unsafe {
+        go_body::process_body(convert_data, (*body_node_ptr).clone());
+    }
+    convert_data.decrement_index_count();
+    convert_data.symbol_table.exit_scope();
+    convert_data.append_indentation();
+    convert_data.code_builder.append("}");
+}
+
+pub fn process_if(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processIf".to_string();
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "if has no condition".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        go_ternary::lower_ternaries(convert_data, &*node.left, type_utils::BOOL_TYPE);
+        if convert_data.is_error() {
+            return;
+        }
+    }
+    convert_data.append_indentation();
+    write_if(convert_data, &node);
+    convert_data.code_builder.append("\n");
+}
+
+fn write_if(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    //Node_type: IfStatement
+    //Token:     if
+    //Left:      condition
+    //Middle:    IfBody
+    //Right:     Else, null without one
+
+    unsafe {
+        let condition_node:&ASTNode = &*node.left;
+
+        convert_data.code_builder.append("if ");
+        go_print_expressions::print_expression(convert_data, condition_node);
+        if convert_data.is_error() {
+            return;
+        }
+        write_block(convert_data, node.middle);
+        if convert_data.is_error() || node.right.is_null() {
+            return;
+        }
+
+        //Node_type: Else
+        //Token:     else
+        //Left:      IfStatement for else if, otherwise ElseBody
+        let else_node:&ASTNode = &*node.right;
+        if else_node.left.is_null() {
+            convert_data.error_detail = "else has no body".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let else_body:&ASTNode = &*else_node.left;
+
+        if else_body.node_type != ASTNodeType::IfStatement {
+            convert_data.code_builder.append(" else");
+            write_block(convert_data, else_node.left);
+            return;
+        }
+
+        //a ternary in an else if has to be worked out inside the else
+        if go_ternary::has_ternary(&*else_body.left) {
+            convert_data.code_builder.append(" else {\n");
+            convert_data.increment_index_count();
+            process_if(convert_data, else_body.clone());
+            convert_data.decrement_index_count();
+            convert_data.append_indentation();
+            convert_data.code_builder.append("}");
+            return;
+        }
+        convert_data.code_builder.append(" else ");
+        write_if(convert_data, else_body);
+    }
+}
+
+/// A loop with a ternary in its condition checks it at the top of the body instead
+fn write_hoisted_condition(convert_data:&mut ConvertData, condition_node:&ASTNode) {
+    go_ternary::lower_ternaries(convert_data, condition_node, type_utils::BOOL_TYPE);
+    if convert_data.is_error() {
+        return;
+    }
+    convert_data.append_indentation();
+    convert_data.code_builder.append("if !(");
+    go_print_expressions::print_expression(convert_data, condition_node);
+    convert_data.code_builder.append(") {\n");
+    convert_data.append_indentation();
+    convert_data.code_builder.append("\tbreak\n");
+    convert_data.append_indentation();
+    convert_data.code_builder.append("}\n");
+}
+
+// The loop body, with the loop's label known to break and continue inside it
+fn write_loop_body(convert_data:&mut ConvertData, body_node_ptr:*mut ASTNode, label:&str) {
+    push_loop_label(convert_data, label, "");
+    write_block(convert_data, body_node_ptr);
+    convert_data.loop_labels.pop();
+}
+
+// { if !(condition) { break } body }, for a condition with a ternary in it
+fn write_hoisted_loop_body(convert_data:&mut ConvertData, condition_node:&ASTNode, body_node_ptr:*mut ASTNode, label:&str) {
+    if body_node_ptr.is_null() {
+        convert_data.error_detail = "loop has no body".to_string();
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+    convert_data.code_builder.append(" {\n");
+    push_loop_label(convert_data, label, "");
+    convert_data.symbol_table.enter_scope();
+    convert_data.increment_index_count();
+
+    write_hoisted_condition(convert_data, condition_node);
+    if convert_data.is_error() == false {
+        unsafe {
+            go_body::process_body(convert_data, (*body_node_ptr).clone());
+        }
+    }
+
+    convert_data.decrement_index_count();
+    convert_data.symbol_table.exit_scope();
+    convert_data.loop_labels.pop();
+    convert_data.append_indentation();
+    convert_data.code_builder.append("}");
+}
+
+fn push_loop_label(convert_data:&mut ConvertData, label:&str, continue_label:&str) {
+    convert_data.loop_labels.push(LoopLabel {
+        name: label.to_string(),
+        continue_label: continue_label.to_string(),
+    });
+}
+
+// outer:, only written when a break or continue uses it as Go rejects unused labels
+fn write_label(convert_data:&mut ConvertData, loop_node:&ASTNode, label:&str, counts_continue:bool) {
+    if label.is_empty() || is_label_used(loop_node, label, counts_continue) == false {
+        return;
+    }
+    convert_data.append_indentation();
+    convert_data.code_builder.append_fmt(format!("{}:\n", label));
+}
+
+fn is_label_used(node:&ASTNode, label:&str, counts_continue:bool) -> bool {
+    let is_loop_exit:bool = node.node_type == ASTNodeType::Break || (counts_continue && node.node_type == ASTNodeType::Continue);
+    unsafe {
+        if is_loop_exit && node.left.is_null() == false {
+            return (*(*node.left).token).text == label;
+        }
+        if node.left.is_null() == false && is_label_used(&*node.left, label, counts_continue) {
+            return true;
+        }
+        if node.middle.is_null() == false && is_label_used(&*node.middle, label, counts_continue) {
+            return true;
+        }
+        if node.right.is_null() == false && is_label_used(&*node.right, label, counts_continue) {
+            return true;
+        }
+    }
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        if is_label_used(&node.children[i], label, counts_continue) {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn is_loop_node(node:&ASTNode) -> bool {
+    return node.node_type == ASTNodeType::WhileLoop
+        || node.node_type == ASTNodeType::ForLoop
+        || node.node_type == ASTNodeType::DoWhileLoop;
+}
+
+/// True if a continue inside node goes to the loop labeled label, an unlabeled one
+/// only counts until it is inside a loop of its own
+fn is_continue_used(node:&ASTNode, label:&str, is_nested:bool) -> bool {
+    unsafe {
+        if node.node_type == ASTNodeType::Continue {
+            if node.left.is_null() {
+                return is_nested == false;
+            }
+            return label.is_empty() == false && (*(*node.left).token).text == label;
+        }
+        let child_nested:bool = is_nested || is_loop_node(node);
+        if node.left.is_null() == false && is_continue_used(&*node.left, label, child_nested) {
+            return true;
+        }
+        if node.middle.is_null() == false && is_continue_used(&*node.middle, label, child_nested) {
+            return true;
+        }
+        if node.right.is_null() == false && is_continue_used(&*node.right, label, child_nested) {
+            return true;
+        }
+        let child_count:usize = node.children.len();
+        for i in 0..child_count {
+            if is_continue_used(&node.children[i], label, child_nested) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+pub fn process_do_while(convert_data:&mut ConvertData, node:ASTNode) {
+    write_do_while(convert_data, &node, "");
+}
+
+// for { { body } __continue_0: if !(condition) { break } }, the inner block keeps
+// the body's declarations out of the way of the goto continue becomes
+fn write_do_while(convert_data:&mut ConvertData, node:&ASTNode, label:&str) {
+
+    convert_data.error_function = "processDoWhile".to_string();
+
+    //Node_type: DoWhileLoop
+    //Token:     do
+    //Left:      condition, checked after each pass
+    //Right:     WhileBody
+
+    unsafe {
+        if node.left.is_null() || node.right.is_null() {
+            convert_data.error_detail = "do while is missing its condition or body".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let condition_node:&ASTNode = &*node.left;
+
+        let mut continue_label:String = String::new();
+        if is_continue_used(&*node.right, label, false) {
+            continue_label = convert_data.create_temp_var(node as *const ASTNode, "continue");
+        }
+
+        //continue never reaches Go's for, so only break needs the label
+        write_label(convert_data, node, label, false);
+        convert_data.append_indentation();
+        convert_data.code_builder.append("for {\n");
+        convert_data.increment_index_count();
+
+        push_loop_label(convert_data, label, &continue_label);
+        convert_data.append_indentation();
+        convert_data.code_builder.append("{\n");
+        convert_data.symbol_table.enter_scope();
+        convert_data.increment_index_count();
+        go_body::process_body(convert_data, (*node.right).clone());
+        convert_data.decrement_index_count();
+        convert_data.symbol_table.exit_scope();
+        convert_data.loop_labels.pop();
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.append_indentation();
+        convert_data.code_builder.append("}\n");
+
+        if continue_label.is_empty() == false {
+            convert_data.append_indentation();
+            convert_data.code_builder.append_fmt(format!("{}:\n", continue_label));
+        }
+        write_hoisted_condition(convert_data, condition_node);
+        if convert_data.is_error() {
+            return;
+        }
+
+        convert_data.decrement_index_count();
+        convert_data.append_indentation();
+        convert_data.code_builder.append("}\n");
+    }
+}
+
+pub fn process_while(convert_data:&mut ConvertData, node:ASTNode) {
+    write_while(convert_data, &node, "");
+}
+
+fn write_while(convert_data:&mut ConvertData, node:&ASTNode, label:&str) {
+
+    convert_data.error_function = "processWhile".to_string();
+
+    //Node_type: WhileLoop
+    //Token:     while
+    //Left:      condition
+    //Right:     WhileBody
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "while has no condition".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let condition_node:&ASTNode = &*node.left;
+
+        write_label(convert_data, node, label, true);
+        convert_data.append_indentation();
+
+        if go_ternary::has_ternary(condition_node) {
+            convert_data.code_builder.append("for");
+            write_hoisted_loop_body(convert_data, condition_node, node.right, label);
+            convert_data.code_builder.append("\n");
+            return;
+        }
+
+        //Go only sees a bare for as never ending, "for true" would need a return after it
+        if type_utils::is_true_literal(condition_node) {
+            convert_data.code_builder.append("for");
+            write_loop_body(convert_data, node.right, label);
+            convert_data.code_builder.append("\n");
+            return;
+        }
+
+        convert_data.code_builder.append("for ");
+        go_print_expressions::print_expression(convert_data, condition_node);
+        if convert_data.is_error() {
+            return;
+        }
+        write_loop_body(convert_data, node.right, label);
+        convert_data.code_builder.append("\n");
+    }
+}
+
+pub fn process_for(convert_data:&mut ConvertData, node:ASTNode) {
+    convert_data.symbol_table.enter_scope();
+    write_for(convert_data, &node, "");
+    convert_data.symbol_table.exit_scope();
+}
+
+fn write_for(convert_data:&mut ConvertData, node:&ASTNode, label:&str) {
+
+    convert_data.error_function = "processFor".to_string();
+
+    //Node_type: ForLoop
+    //Token:     for
+    //Left:      ForCondition
+    //Right:     ForBody
+
+    //Node_type: ForCondition
+    //Token:     for
+    //Left:      Declaration or Assignment run once, null if empty
+    //Middle:    condition, null if empty
+    //Right:     Assignment run after each pass, null if empty
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "for has no condition".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let for_token:Token = (*node.token).clone();
+        let for_condition:&ASTNode = &*node.left;
+
+        //the first part runs once so its ternaries can go before the loop
+        if for_condition.left.is_null() == false {
+            go_ternary::lower_ternaries(convert_data, &*for_condition.left, "");
+            if convert_data.is_error() {
+                return;
+            }
+        }
+        //the symbol has to exist before the condition and step are checked
+        if for_condition.left.is_null() == false && (*for_condition.left).node_type == ASTNodeType::Declaration {
+            let declaration:&ASTNode = &*for_condition.left;
+            let name_token:Token = (*declaration.token).clone();
+            let type_text:String = type_utils::type_node_to_string(declaration.left);
+            convert_data.symbol_table.add_symbol(&name_token.text, &type_text, SymbolKind::Variable, name_token.clone(), declaration.is_const);
+        }
+
+        let mut condition_has_ternary:bool = false;
+        if for_condition.middle.is_null() == false {
+            condition_has_ternary = go_ternary::has_ternary(&*for_condition.middle);
+        }
+        if for_condition.right.is_null() == false {
+            let step_node:&ASTNode = &*for_condition.right;
+            if go_ternary::has_ternary(step_node) {
+                convert_data.error_token = for_token;
+                convert_data.error_detail = "a ternary can't be used in a for loop's last part, change the value inside the loop instead".to_string();
+                convert_data.error_code = ConvertError::UnimplementedNodeType;
+                return;
+            }
+        }
+
+        write_label(convert_data, node, label, true);
+        convert_data.append_indentation();
+        convert_data.code_builder.append("for");
+
+        //for (; i < 10;) is just a while
+        let has_clauses:bool = for_condition.left.is_null() == false || for_condition.right.is_null() == false;
+        if has_clauses {
+            convert_data.code_builder.append(" ");
+            if for_condition.left.is_null() == false {
+                write_for_init(convert_data, &*for_condition.left);
+                if convert_data.is_error() {
+                    return;
+                }
+            }
+            convert_data.code_builder.append(";");
+        }
+        let is_endless:bool = for_condition.middle.is_null() == false && type_utils::is_true_literal(&*for_condition.middle);
+        if for_condition.middle.is_null() == false && condition_has_ternary == false && is_endless == false {
+            convert_data.code_builder.append(" ");
+            go_print_expressions::print_expression(convert_data, &*for_condition.middle);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+        if has_clauses {
+            convert_data.code_builder.append(";");
+            if for_condition.right.is_null() == false {
+                convert_data.code_builder.append(" ");
+                write_for_step(convert_data, &*for_condition.right);
+                if convert_data.is_error() {
+                    return;
+                }
+            }
+        }
+
+        if condition_has_ternary {
+            write_hoisted_loop_body(convert_data, &*for_condition.middle, node.right, label);
+        } else {
+            write_loop_body(convert_data, node.right, label);
+        }
+        convert_data.code_builder.append("\n");
+    }
+}
+
+// Go's for can't hold a var, so i32 i = 0 becomes i := int32(0)
+fn write_for_init(convert_data:&mut ConvertData, init_node:&ASTNode) {
+    if init_node.node_type == ASTNodeType::Assignment {
+        go_declarations::write_assignment(convert_data, init_node);
+        return;
+    }
+    unsafe {
+        let name_token:Token = (*init_node.token).clone();
+        let type_text:String = type_utils::type_node_to_string(init_node.left);
+        go_utils::use_type_imports(convert_data, &type_text);
+
+        let mut go_type:String = go_utils::type_node_to_go_type(init_node.left);
+        //*int32(p) would dereference the conversion
+        if go_type.starts_with('*') || go_type.starts_with("func") || go_type.starts_with("chan") {
+            go_type = format!("({})", go_type);
+        }
+        convert_data.code_builder.append_fmt(format!("{} := {}(", name_token.text, go_type));
+        go_print_expressions::print_expression(convert_data, &*init_node.right);
+        convert_data.code_builder.append(")");
+    }
+}
+
+fn write_for_step(convert_data:&mut ConvertData, step_node:&ASTNode) {
+    if step_node.node_type == ASTNodeType::Assignment {
+        go_declarations::write_assignment(convert_data, step_node);
+        return;
+    }
+    go_print_expressions::print_expression(convert_data, step_node);
+}
+
+pub fn process_label(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processLabel".to_string();
+
+    //Node_type: Label
+    //Token:     label name
+    //Left:      the loop it names
+
+    unsafe {
+        let label_token:Token = (*node.token).clone();
+        let label:String = label_token.text.clone();
+
+        //Go labels belong to the whole function, not the loop
+        let label_count:usize = convert_data.function_labels.len();
+        for i in 0..label_count {
+            if convert_data.function_labels[i] == label {
+                convert_data.error_token = label_token;
+                convert_data.error_detail = format!("label '{}' is already used in this function", label);
+                convert_data.error_code = ConvertError::InvalidLabel;
+                return;
+            }
+        }
+        convert_data.function_labels.push(label.clone());
+
+        let loop_node:&ASTNode = &*node.left;
+        if loop_node.node_type == ASTNodeType::WhileLoop {
+            write_while(convert_data, loop_node, &label);
+            return;
+        }
+        if loop_node.node_type == ASTNodeType::DoWhileLoop {
+            write_do_while(convert_data, loop_node, &label);
+            return;
+        }
+        convert_data.symbol_table.enter_scope();
+        write_for(convert_data, loop_node, &label);
+        convert_data.symbol_table.exit_scope();
+    }
+}
+
+pub fn process_loop_exit(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processLoopExit".to_string();
+
+    //Node_type: Break or Continue
+    //Token:     break or continue
+    //Left:      Identifier naming the loop's label, null for the innermost loop
+
+    unsafe {
+        let exit_token:Token = (*node.token).clone();
+
+        if convert_data.loop_labels.is_empty() {
+            convert_data.error_token = exit_token.clone();
+            convert_data.error_detail = format!("'{}' can only be used inside a loop", exit_token.text);
+            convert_data.error_code = ConvertError::InvalidLabel;
+            return;
+        }
+
+        let is_continue:bool = node.node_type == ASTNodeType::Continue;
+
+        if node.left.is_null() {
+            let last_index:usize = convert_data.loop_labels.len() - 1;
+            let continue_label:String = convert_data.loop_labels[last_index].continue_label.clone();
+            convert_data.append_indentation();
+            if is_continue && continue_label.is_empty() == false {
+                convert_data.code_builder.append_fmt(format!("goto {}\n", continue_label));
+                return;
+            }
+            convert_data.code_builder.append_fmt(format!("{}\n", exit_token.text));
+            return;
+        }
+
+        let label_token:Token = (*(*node.left).token).clone();
+        let mut loop_index:usize = convert_data.loop_labels.len();
+        let label_count:usize = convert_data.loop_labels.len();
+        for i in 0..label_count {
+            if convert_data.loop_labels[i].name == label_token.text {
+                loop_index = i;
+            }
+        }
+        if loop_index == label_count {
+            convert_data.error_token = label_token.clone();
+            convert_data.error_detail = format!("no loop around this {} is labeled '{}'", exit_token.text, label_token.text);
+            convert_data.error_code = ConvertError::InvalidLabel;
+            return;
+        }
+
+        //a do while's continue goes to its condition check
+        let continue_label:String = convert_data.loop_labels[loop_index].continue_label.clone();
+        convert_data.append_indentation();
+        if is_continue && continue_label.is_empty() == false {
+            convert_data.code_builder.append_fmt(format!("goto {}\n", continue_label));
+            return;
+        }
+        convert_data.code_builder.append_fmt(format!("{} {}\n", exit_token.text, label_token.text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    #[test]
+    fn do_while_continue_still_checks_the_condition() {
+        let code:String = "fn void main() {\n    i32 i = 0;\n    do {\n        i++;\n        if (i == 2) {\n            continue;\n        }\n        println(i);\n    } while (i < 5);\n}\n".to_string();
+        let go_code:String = test_utils::convert_code(&code);
+        assert!(go_code.contains("\t\t\t\tgoto __continue_0\n"), "{}", go_code);
+        assert!(go_code.contains("\t\t}\n\t\t__continue_0:\n\t\tif !(i < 5) {\n\t\t\tbreak\n\t\t}\n"), "{}", go_code);
+    }
+}
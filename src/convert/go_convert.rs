@@ -1,89 +1,173 @@
-use crate::convert::go_functions;
-use crate::core::enums;
-use crate::core::errors;
-use crate::core::printing;
-use crate::core::structs;
-use crate::core::structs::get_default_node;
-use enums::ASTNodeType;
-use errors::ConvertError;
-use structs::ASTNode;
-use structs::ConvertData;
-use structs::StringBuilder;
-use structs::Token;
-
-pub fn convert(ast_nodes:&Vec<ASTNode>, code:&String, convert_error:&mut i32) -> String {
-    print!("\t{}Converting{}\t\t", printing::GREY, printing::RESET);
-
-    let mut code_builder = StringBuilder::new();
-
-    let mut convert_data = ConvertData {
-        ast_nodes: &ast_nodes,
-        code_builder: &mut code_builder,
-        error_code: ConvertError::None,
-        error_detail: String::new(),
-        error_token: structs::empty_token(),
-        error_function: String::new(),
-        function_return_type: String::new(),
-        index_count: 0,
-        node_index: 0,
-        temp_var_count: 0,
-    };
-
-    convert_data.error_function = "convert".to_string();
-
-    let node_count: usize = convert_data.ast_nodes.len();
-    if node_count == 0 {
-        *convert_error = ConvertError::NoAstNodes;
-        return String::new();
-    }
-
-    convert_data.code_builder.append_line("package main\n\nimport \"fmt\"\n\n");
-
-    while convert_data.node_index < node_count {
-
-        let previous_index:usize = convert_data.node_index;
-
-        process_global_node(&mut convert_data);
-        if convert_data.is_error() {
-            print!("\t{}Error{}\n", printing::RED, printing::RESET);
-            //debugging::printConvertError(convert_data, code);
-            *convert_error = convert_data.error_code;
-            return String::new();
-        };
-
-        if previous_index == convert_data.node_index {
-            convert_data.node_index += 1;
-        }
-    }
-    print!("\t{}Done{}\n", printing::CYAN, printing::RESET);
-
-    let generated_code:StringBuilder = convert_data.code_builder.clone();
-    return generated_code.into_string();
-}
-
-fn process_global_node(convert_data: &mut ConvertData) {
-    
-    convert_data.error_function = "processGlobalNode".to_string();
-
-    let node: ASTNode = convert_data.get_node();
-    if convert_data.is_error() {
-        return;
-    }
-
-    unsafe {
-        match node.node_type {
-            ASTNodeType::FunctionDeclaration => {
-                go_functions::process_function_declaration(convert_data, node)
-            }
-            _ => {
-                let token_ptr:*mut Token = node.token;
-                let error_token:Token = (*token_ptr).clone();
-
-                convert_data.error_token = error_token;
-                convert_data.error_detail = format!("{} not implemented yet", node.node_type);
-                convert_data.error_code = ConvertError::UnimplementedNodeType;
-                return;
-            }
-        }
-    }
-}
+use crate::convert::go_declarations;
+use crate::convert::go_functions;
+use crate::convert::go_helpers;
+use crate::convert::go_imports;
+use crate::convert::go_structs;
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::printing;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::SemanticResult;
+use structs::SourceFile;
+use structs::StringBuilder;
+use structs::SymbolTable;
+use structs::Token;
+
+/// semantic_result has the declarations the semantic pass found are never read and the generic functions' constraints
+pub fn convert(ast_nodes:&Vec<ASTNode>, source_files:&Vec<SourceFile>, semantic_result:&SemanticResult, convert_error:&mut i32) -> String {
+    print!("\t{}Converting{}\t\t", printing::GREY, printing::RESET);
+
+    let mut code_builder = StringBuilder::new();
+
+    let mut convert_data = ConvertData {
+        ast_nodes,
+        code_builder: &mut code_builder,
+        error_code: ConvertError::None,
+        error_detail: String::new(),
+        error_token: structs::empty_token(),
+        error_function: String::new(),
+        function_return_type: String::new(),
+        index_count: 0,
+        node_index: 0,
+        temp_var_count: 0,
+        symbol_table: SymbolTable::new(),
+        temp_vars: Vec::new(),
+        helpers: Vec::new(),
+        go_imports: Vec::new(),
+        source_paths: Vec::new(),
+        loop_labels: Vec::new(),
+        function_labels: Vec::new(),
+        unused_variables: &semantic_result.unused_variables,
+        generic_constraints: &semantic_result.generic_constraints,
+    };
+
+    let source_count: usize = source_files.len();
+    for i in 0..source_count {
+        convert_data.source_paths.push(source_files[i].path.clone());
+    }
+
+    convert_data.error_function = "convert".to_string();
+
+    let node_count: usize = convert_data.ast_nodes.len();
+    if node_count == 0 {
+        *convert_error = ConvertError::NoAstNodes;
+        return String::new();
+    }
+
+    //functions and structs can be used before they are declared, globals from any function
+    register_global_symbols(&mut convert_data);
+
+    while convert_data.node_index < node_count {
+
+        let previous_index:usize = convert_data.node_index;
+
+        process_global_node(&mut convert_data);
+        if convert_data.is_error() {
+            print!("\t{}Error{}\n", printing::RED, printing::RESET);
+            debugging::print_convert_error(&convert_data, source_files);
+            *convert_error = convert_data.error_code;
+            return String::new();
+        };
+
+        if previous_index == convert_data.node_index {
+            convert_data.node_index += 1;
+        }
+    }
+    go_helpers::write_helpers(&mut convert_data);
+    print!("\t{}Done{}\n", printing::CYAN, printing::RESET);
+
+    //the imports are only known once everything else is written
+    let mut file_builder:StringBuilder = StringBuilder::new();
+    file_builder.append("package main\n\n");
+    go_imports::write_imports(&mut file_builder, &convert_data.go_imports);
+    file_builder.append(convert_data.code_builder.as_str());
+    return file_builder.into_string();
+}
+
+fn process_global_node(convert_data: &mut ConvertData) {
+    
+    convert_data.error_function = "processGlobalNode".to_string();
+
+    let node: ASTNode = convert_data.get_node();
+    if convert_data.is_error() {
+        return;
+    }
+
+    unsafe {
+        match node.node_type {
+            ASTNodeType::FunctionDeclaration => {
+                go_functions::process_function_declaration(convert_data, node)
+            }
+            ASTNodeType::StructDeclaration => {
+                go_structs::process_struct_declaration(convert_data, node)
+            }
+            ASTNodeType::Declaration => {
+                go_declarations::process_declaration(convert_data, node);
+                //a run of globals is one block, set apart like a function
+                let next_index:usize = convert_data.node_index + 1;
+                let is_last_global:bool = next_index >= convert_data.ast_nodes.len() || convert_data.ast_nodes[next_index].node_type != ASTNodeType::Declaration;
+                if convert_data.is_error() == false && is_last_global {
+                    convert_data.code_builder.append("\n");
+                }
+            }
+            _ => {
+                let token_ptr:*mut Token = node.token;
+                let error_token:Token = (*token_ptr).clone();
+
+                convert_data.error_token = error_token;
+                convert_data.error_detail = format!("{} not implemented yet", node.node_type);
+                convert_data.error_code = ConvertError::UnimplementedNodeType;
+                return;
+            }
+        }
+    }
+}
+
+fn register_global_symbols(convert_data: &mut ConvertData) {
+
+    let node_count: usize = convert_data.ast_nodes.len();
+
+    for i in 0..node_count {
+        let node: &ASTNode = &convert_data.ast_nodes[i];
+        if node.token.is_null() {
+            continue;
+        }
+        unsafe {
+            let name_token: Token = (*node.token).clone();
+
+            if node.node_type == ASTNodeType::FunctionDeclaration {
+                let type_text: String = type_utils::function_declaration_type_text(node);
+                let type_parameters: Vec<String> = type_utils::get_type_parameter_names(node);
+                convert_data.symbol_table.add_function_symbol(&name_token.text, &type_text, name_token.clone(), type_parameters);
+                continue;
+            }
+            if node.node_type == ASTNodeType::Declaration {
+                let type_text: String = type_utils::type_node_to_string(node.left);
+                convert_data.symbol_table.add_symbol(&name_token.text, &type_text, SymbolKind::Variable, name_token.clone(), node.is_const);
+                continue;
+            }
+            if node.node_type != ASTNodeType::StructDeclaration {
+                continue;
+            }
+
+            convert_data.symbol_table.add_symbol(&name_token.text, &name_token.text, SymbolKind::Struct, name_token.clone(), true);
+
+            //fields are looked up as "Struct.field"
+            let field_count: usize = node.children.len();
+            for field_index in 0..field_count {
+                let field: &ASTNode = &node.children[field_index];
+                let field_token: Token = (*field.token).clone();
+                let field_name: String = type_utils::get_field_symbol_name(&name_token.text, &field_token.text);
+                let field_type: String = type_utils::type_node_to_string(field.left);
+                convert_data.symbol_table.add_symbol(&field_name, &field_type, SymbolKind::Field, field_token, false);
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::convert::go_print_expressions;
+use crate::convert::go_ternary;
+use crate::convert::go_utils;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::SymbolKind;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::Token;
+
+pub fn process_declaration(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processDeclaration".to_string();
+
+    //Node_type: Declaration
+    //Token:     variable name
+    //Left:      type node
+    //Right:     value, null if declared without one
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "node.left is null in declaration".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let name_token:Token = (*node.token).clone();
+        let type_text:String = type_utils::type_node_to_string(node.left);
+        let go_type:String = go_utils::type_node_to_go_type(node.left);
+
+        if node.right.is_null() == false {
+            go_ternary::lower_ternaries(convert_data, &*node.right, &type_text);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+
+        go_utils::use_type_imports(convert_data, &type_text);
+        convert_data.append_indentation();
+        convert_data.code_builder.append_fmt(format!("var {} {}", name_token.text, go_type));
+
+        if node.right.is_null() == false {
+            convert_data.code_builder.append(" = ");
+            go_print_expressions::print_expression(convert_data, &*node.right);
+            if convert_data.is_error() {
+                return;
+            }
+        } else {
+            //channels and sync types are ready to use once declared
+            let initial_value:String = go_utils::get_initial_go_value(&type_text);
+            if initial_value.is_empty() == false {
+                convert_data.code_builder.append_fmt(format!(" = {}", initial_value));
+            }
+        }
+        convert_data.code_builder.append("\n");
+        write_unused_variable(convert_data, &name_token);
+
+        convert_data.symbol_table.add_symbol(&name_token.text, &type_text, SymbolKind::Variable, name_token.clone(), node.is_const);
+    }
+}
+
+// Go won't compile a local that is never read, _ = x counts as reading it
+fn write_unused_variable(convert_data:&mut ConvertData, name_token:&Token) {
+    if convert_data.is_unused_variable(name_token) == false {
+        return;
+    }
+    convert_data.append_indentation();
+    convert_data.code_builder.append_fmt(format!("_ = {}\n", name_token.text));
+}
+
+pub fn process_multiple_declaration(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processMultipleDeclaration".to_string();
+
+    //Node_type: MultipleDeclaration
+    //Token:     '='
+    //Children:  Declaration nodes without values
+    //Right:     value giving one result per declaration
+
+    unsafe {
+        if node.right.is_null() {
+            convert_data.error_detail = "node.right is null in multiple declaration".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let value_node:&ASTNode = &*node.right;
+        let declaration_count:usize = node.children.len();
+
+        go_ternary::lower_ternaries(convert_data, value_node, "");
+        if convert_data.is_error() {
+            return;
+        }
+
+        //var value int32
+        //var ok bool
+        //value, ok = __read_i32()
+        for i in 0..declaration_count {
+            let declaration:&ASTNode = &node.children[i];
+            let go_type:String = go_utils::type_node_to_go_type(declaration.left);
+            go_utils::use_type_imports(convert_data, &type_utils::type_node_to_string(declaration.left));
+            convert_data.append_indentation();
+            convert_data.code_builder.append_fmt(format!("var {} {}\n", (*declaration.token).text, go_type));
+        }
+
+        convert_data.append_indentation();
+        for i in 0..declaration_count {
+            if i != 0 {
+                convert_data.code_builder.append(", ");
+            }
+            convert_data.code_builder.append((*node.children[i].token).text.as_str());
+        }
+        convert_data.code_builder.append(" = ");
+        go_print_expressions::print_expression(convert_data, value_node);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append("\n");
+
+        for i in 0..declaration_count {
+            let declaration:&ASTNode = &node.children[i];
+            let name_token:Token = (*declaration.token).clone();
+            let type_text:String = type_utils::type_node_to_string(declaration.left);
+            write_unused_variable(convert_data, &name_token);
+            convert_data.symbol_table.add_symbol(&name_token.text, &type_text, SymbolKind::Variable, name_token.clone(), declaration.is_const);
+        }
+    }
+}
+
+pub fn process_assignment(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processAssignment".to_string();
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "assignment is missing its target".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        go_ternary::lower_ternaries(convert_data, &*node.left, "");
+        if convert_data.is_error() {
+            return;
+        }
+        if node.right.is_null() == false {
+            let target_type:String = type_utils::get_expression_type(&*node.left, &convert_data.symbol_table);
+            go_ternary::lower_ternaries(convert_data, &*node.right, &target_type);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+    }
+
+    convert_data.append_indentation();
+    write_assignment(convert_data, &node);
+    convert_data.code_builder.append("\n");
+}
+
+/// x = value, x += value or x++ without indentation or a new line
+pub fn write_assignment(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let operator_token:Token = (*node.token).clone();
+
+        go_print_expressions::print_expression(convert_data, &*node.left);
+        if convert_data.is_error() {
+            return;
+        }
+        if node.right.is_null() {
+            convert_data.code_builder.append(operator_token.text.as_str());
+            return;
+        }
+        convert_data.code_builder.append_fmt(format!(" {} ", operator_token.text));
+        go_print_expressions::print_expression(convert_data, &*node.right);
+    }
+}
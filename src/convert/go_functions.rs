@@ -1,136 +1,312 @@
-
-
-use crate::core::errors;
-use crate::core::structs;
-use crate::convert::go_body;
-use crate::convert::go_utils; 
-use structs::ASTNode;
-use structs::Token;
-use structs::ConvertData;
-use errors::ConvertError;
-
-
-pub fn process_function_declaration(convert_data:&mut ConvertData, node:ASTNode) {
-
-    convert_data.error_function = "process_function_declaration".to_string();
-
-    //Node_type: ASTNode_FunctionDeclaration,
-    //Token:     third_token, - function name
-    //Left:      &type_node, - return type node (i32, etc.)
-    //Middle:    nil, - parameters
-    //Right:     function_body_node, - function body
-
-    write_function_name_and_parameters(convert_data, &node);
-
-    if node.right.is_null() {
-        convert_data.error_detail = "node.Right is null".to_string();
-        convert_data.error_code = ConvertError::NodeIsNull;
-        return;
-    }
-
-    convert_data.increment_index_count();
-    unsafe  {
-        go_body::process_body(convert_data, (*node.right).clone());
-    }
-    convert_data.decrement_index_count();
-
-    convert_data.code_builder.append("\n\r}\n\n");
-}
-
-fn write_function_name_and_parameters(convert_data:&mut ConvertData, node:&ASTNode) {
-
-    unsafe {
-    convert_data.error_function = "writeFunctionNameAndParameters".to_string();
-
-    if node.left.is_null() {
-        convert_data.error_detail = "node.Left is null".to_string();
-        convert_data.error_code = ConvertError::NodeIsNull;
-        return;
-    }
-
-    let function_name_ptr:*mut Token = node.token;
-    if function_name_ptr.is_null() {
-        convert_data.error_code = ConvertError::NodeIsNull;
-        return;
-    }
-    let function_name_token:Token = (*function_name_ptr).clone();
-
-    //write declaration
-    let return_type_text:String = convert_data.print_type(node.left).text;
-
-    convert_data.code_builder.append_fmt(format!("func {}(", function_name_token.text));
-
-    if node.middle.is_null() == false {
-        print_parameters(convert_data, node);
-    }
-
-    if return_type_text == "void" {
-        convert_data.code_builder.append(") {\n");
-        return;
-    }
-
-    convert_data.code_builder.append_fmt(format!(") {} ", return_type_text));
-    convert_data.code_builder.append("{\n");
-    }
-}
-
-fn print_parameters(convert_data:&mut ConvertData, node:&ASTNode) {
-
-    unsafe {
-    if node.middle.is_null() {
-        convert_data.error_detail = "Internal error: node.middle is null in printParameters".to_string();
-        convert_data.error_code = ConvertError::NodeIsNull;
-        return;
-    }
-
-    let middle_ptr:*mut ASTNode = node.middle;
-    let middle_node:ASTNode = (*middle_ptr).clone();
-
-    let child_count:usize = middle_node.children.len();
-
-    let child_list:Vec<ASTNode> = middle_node.children;
-
-    if child_count == 0 {
-        return;
-    }
-    for i in 0..child_count {
-
-        let child:&ASTNode = &child_list[i];
-
-        let parameter_token_ptr:*mut Token = child.token;
-        let parameter_token:Token = (*parameter_token_ptr).clone();
-        let parameter_name:String = parameter_token.text;
-
-        let type_node_ptr:*mut ASTNode = child.left;
-        if type_node_ptr.is_null() {
-            convert_data.error_detail = "Internal error: type_node is null in printParameters".to_string();
-            return;
-        }
-
-        //Recursively loop for the last node and expect it be to a type
-        let mut base_type_node_ptr:*mut ASTNode = type_node_ptr;
-        while (*base_type_node_ptr).left.is_null() == false {
-            base_type_node_ptr = (*base_type_node_ptr).left;
-        }
-
-        if base_type_node_ptr.is_null() {
-            convert_data.error_detail = "Internal error: base_type_node is null in printParameters".to_string();
-            convert_data.error_code = ConvertError::NodeIsNull;
-            return;
-        }
-
-        let base_type_node:ASTNode = (*base_type_node_ptr).clone();
-        let base_type_token_ptr:*mut Token = base_type_node.token;
-        let base_type_token:Token = (*base_type_token_ptr).clone();
-
-        let var_type:&str = go_utils::convert_type_to_go_type(base_type_token.token_type);
-
-        convert_data.code_builder.append_fmt(format!("{} {}", parameter_name, var_type));
-
-
-        if i < child_count - 1 {
-            convert_data.code_builder.append(", ");
-        }
-    }
-}
-}
\ No newline at end of file
+
+
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use crate::convert::go_body;
+use crate::convert::go_generics;
+use crate::convert::go_imports;
+use crate::convert::go_print_expressions;
+use crate::convert::go_ternary;
+use crate::convert::go_utils; 
+use enums::SymbolKind;
+use structs::ASTNode;
+use structs::Token;
+use structs::ConvertData;
+use errors::ConvertError;
+
+const MAIN_NAME:&str = "main";
+//no user function can clash with it, the semantic pass keeps names starting with __ for the compiler
+const WRAPPED_MAIN_NAME:&str = "__main";
+
+
+pub fn process_function_declaration(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "process_function_declaration".to_string();
+
+    //Node_type: ASTNode_FunctionDeclaration,
+    //Token:     third_token, - function name
+    //Left:      &type_node, - return type node (i32, etc.)
+    //Middle:    nil, - parameters
+    //Right:     function_body_node, - function body
+    //Children:  TypeParameter nodes, empty unless generic
+
+    check_main_signature(convert_data, &node);
+    if convert_data.is_error() {
+        return;
+    }
+
+    write_function_name_and_parameters(convert_data, &node);
+
+    if node.right.is_null() {
+        convert_data.error_detail = "node.Right is null".to_string();
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+
+    convert_data.function_return_type = type_utils::type_node_to_string(node.left);
+    convert_data.function_labels.clear();
+
+    convert_data.increment_index_count();
+    unsafe  {
+        go_body::process_body(convert_data, (*node.right).clone());
+    }
+    convert_data.decrement_index_count();
+    convert_data.symbol_table.exit_scope();
+
+    convert_data.code_builder.append("}\n\n");
+
+    if is_wrapped_main(&node) {
+        write_main_wrapper(convert_data, &node);
+    }
+}
+
+/// Go's main takes nothing and returns nothing, so a main taking args or returning
+/// an exit code is written as __main and called from a real main
+fn is_wrapped_main(node:&ASTNode) -> bool {
+    unsafe {
+        if (*node.token).text != MAIN_NAME {
+            return false;
+        }
+        let return_type:String = type_utils::type_node_to_string(node.left);
+        let has_parameters:bool = node.middle.is_null() == false && (*node.middle).children.len() > 0;
+        return has_parameters || return_type != type_utils::VOID_TYPE;
+    }
+}
+
+// main can take string[] args and return an integer exit code
+fn check_main_signature(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        if name_token.text != MAIN_NAME {
+            return;
+        }
+        if node.children.len() > 0 {
+            convert_data.error_token = name_token;
+            convert_data.error_detail = "main can't be generic".to_string();
+            convert_data.error_code = ConvertError::InvalidMainSignature;
+            return;
+        }
+
+        let return_type:String = type_utils::type_node_to_string(node.left);
+        let is_exit_code:bool = type_utils::is_integer_type_text(&return_type);
+        if return_type != type_utils::VOID_TYPE && is_exit_code == false {
+            convert_data.error_token = name_token;
+            convert_data.error_detail = format!("main returns 'void' or an integer exit code, found '{}'", return_type);
+            convert_data.error_code = ConvertError::InvalidMainSignature;
+            return;
+        }
+
+        if node.middle.is_null() {
+            return;
+        }
+        let parameters:&ASTNode = &*node.middle;
+        let parameter_count:usize = parameters.children.len();
+        if parameter_count == 0 {
+            return;
+        }
+        let parameter_type:String = type_utils::type_node_to_string(parameters.children[0].left);
+        if parameter_count > 1 || parameter_type != "string[]" {
+            convert_data.error_token = name_token;
+            convert_data.error_detail = "main takes nothing or 'string[] args'".to_string();
+            convert_data.error_code = ConvertError::InvalidMainSignature;
+            return;
+        }
+    }
+}
+
+// func main() {
+//     os.Exit(int(__main(os.Args)))
+// }
+fn write_main_wrapper(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let return_type:String = type_utils::type_node_to_string(node.left);
+        let has_parameters:bool = node.middle.is_null() == false && (*node.middle).children.len() > 0;
+
+        convert_data.add_import(go_imports::OS);
+
+        let mut call_text:String = WRAPPED_MAIN_NAME.to_string();
+        if has_parameters {
+            call_text.push_str("(os.Args)");
+        } else {
+            call_text.push_str("()");
+        }
+
+        convert_data.code_builder.append("func main() {\n");
+        if return_type == type_utils::VOID_TYPE {
+            convert_data.code_builder.append_fmt(format!("\t{}\n", call_text));
+        } else {
+            convert_data.code_builder.append_fmt(format!("\tos.Exit(int({}))\n", call_text));
+        }
+        convert_data.code_builder.append("}\n\n");
+    }
+}
+
+pub fn process_return(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processReturn".to_string();
+
+    //Node_type: Return
+    //Token:     return token
+    //Left:      returned value, null for 'return;'
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.append_indentation();
+            convert_data.code_builder.append("return\n");
+            return;
+        }
+
+        let value_node:&ASTNode = &*node.left;
+        let return_type:String = convert_data.function_return_type.clone();
+        go_ternary::lower_ternaries(convert_data, value_node, &return_type);
+        if convert_data.is_error() {
+            return;
+        }
+
+        convert_data.append_indentation();
+        convert_data.code_builder.append("return ");
+        go_print_expressions::print_expression(convert_data, value_node);
+        convert_data.code_builder.append("\n");
+    }
+}
+
+fn write_function_name_and_parameters(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    unsafe {
+    convert_data.error_function = "writeFunctionNameAndParameters".to_string();
+
+    if node.left.is_null() {
+        convert_data.error_detail = "node.Left is null".to_string();
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+
+    let function_name_ptr:*mut Token = node.token;
+    if function_name_ptr.is_null() {
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+    let function_name_token:Token = (*function_name_ptr).clone();
+
+    //write declaration
+    let return_type_text:String = go_utils::type_node_to_go_type(node.left);
+    go_utils::use_type_imports(convert_data, &type_utils::type_node_to_string(node.left));
+
+    let mut go_name:String = function_name_token.text.clone();
+    if is_wrapped_main(node) {
+        go_name = WRAPPED_MAIN_NAME.to_string();
+    }
+    convert_data.code_builder.append_fmt(format!("func {}", go_name));
+    go_generics::write_type_parameters(convert_data, node);
+    convert_data.code_builder.append("(");
+
+    //parameters live in the function's scope
+    convert_data.symbol_table.enter_scope();
+    go_generics::add_type_parameter_symbols(convert_data, node);
+
+    if node.middle.is_null() == false {
+        print_parameters(convert_data, node);
+    }
+
+    if return_type_text.is_empty() {
+        convert_data.code_builder.append(") {\n");
+        return;
+    }
+
+    convert_data.code_builder.append_fmt(format!(") {} ", return_type_text));
+    convert_data.code_builder.append("{\n");
+    }
+}
+
+fn print_parameters(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    unsafe {
+    if node.middle.is_null() {
+        convert_data.error_detail = "Internal error: node.middle is null in printParameters".to_string();
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+
+    let middle_ptr:*mut ASTNode = node.middle;
+    let middle_node:ASTNode = (*middle_ptr).clone();
+
+    let child_count:usize = middle_node.children.len();
+
+    let child_list:Vec<ASTNode> = middle_node.children;
+
+    if child_count == 0 {
+        return;
+    }
+    for i in 0..child_count {
+
+        let child:&ASTNode = &child_list[i];
+
+        let parameter_token_ptr:*mut Token = child.token;
+        let parameter_token:Token = (*parameter_token_ptr).clone();
+        let parameter_name:String = parameter_token.text.clone();
+
+        let type_node_ptr:*mut ASTNode = child.left;
+        if type_node_ptr.is_null() {
+            convert_data.error_detail = "Internal error: type_node is null in printParameters".to_string();
+            return;
+        }
+
+        let var_type:String = go_utils::type_node_to_go_type(type_node_ptr);
+
+        convert_data.code_builder.append_fmt(format!("{} {}", parameter_name, var_type));
+
+        let type_text:String = type_utils::type_node_to_string(type_node_ptr);
+        go_utils::use_type_imports(convert_data, &type_text);
+        convert_data.symbol_table.add_symbol(&parameter_name, &type_text, SymbolKind::Parameter, parameter_token.clone(), (*type_node_ptr).is_const);
+
+
+        if i < child_count - 1 {
+            convert_data.code_builder.append(", ");
+        }
+    }
+}
+}
+pub fn process_function_call(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processFunctionCall".to_string();
+
+    go_ternary::lower_ternaries(convert_data, &node, "");
+    if convert_data.is_error() {
+        return;
+    }
+
+    convert_data.append_indentation();
+    go_print_expressions::print_expression(convert_data, &node);
+    convert_data.code_builder.append("\n");
+}
+
+pub fn process_spawn(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processSpawn".to_string();
+
+    //Node_type: Spawn
+    //Token:     spawn
+    //Left:      FunctionCall run on its own goroutine
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "node.left is null in spawn".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let call_node:&ASTNode = &*node.left;
+
+        //arguments are worked out before the goroutine starts, as Go does
+        go_ternary::lower_ternaries(convert_data, call_node, "");
+        if convert_data.is_error() {
+            return;
+        }
+
+        convert_data.append_indentation();
+        convert_data.code_builder.append("go ");
+        go_print_expressions::print_expression(convert_data, call_node);
+        convert_data.code_builder.append("\n");
+    }
+}
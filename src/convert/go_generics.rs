@@ -0,0 +1,96 @@
+use crate::convert::go_helpers;
+use crate::convert::go_utils;
+use crate::core::enums;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::Token;
+
+/// Type parameters are names in the function's scope, so "T" can be told apart from a struct
+pub fn add_type_parameter_symbols(convert_data:&mut ConvertData, node:&ASTNode) {
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        let child:&ASTNode = &node.children[i];
+        if child.node_type != ASTNodeType::TypeParameter || child.token.is_null() {
+            continue;
+        }
+        unsafe {
+            let name_token:Token = (*child.token).clone();
+            convert_data.symbol_table.add_symbol(&name_token.text, &name_token.text, SymbolKind::TypeParameter, name_token.clone(), true);
+        }
+    }
+}
+
+/// Writes "[T __Ordered]" after a generic function's name, nothing for a normal function
+pub fn write_type_parameters(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    convert_data.error_function = "writeTypeParameters".to_string();
+
+    let type_parameters:Vec<String> = type_utils::get_type_parameter_names(node);
+    let parameter_count:usize = type_parameters.len();
+    if parameter_count == 0 || node.token.is_null() {
+        return;
+    }
+
+    let mut constraints:Vec<usize> = vec![type_utils::CONSTRAINT_ANY; parameter_count];
+    unsafe {
+        let generic_index:usize = type_utils::get_generic_constraints_index(convert_data.generic_constraints, &(*node.token).text);
+        if generic_index < convert_data.generic_constraints.len() {
+            constraints = convert_data.generic_constraints[generic_index].constraints.clone();
+        }
+    }
+
+    convert_data.code_builder.append("[");
+    for i in 0..parameter_count {
+        if i != 0 {
+            convert_data.code_builder.append(", ");
+        }
+        let constraint_name:String = get_constraint_name(convert_data, constraints[i]);
+        convert_data.code_builder.append_fmt(format!("{} {}", type_parameters[i], constraint_name));
+    }
+    convert_data.code_builder.append("]");
+}
+
+fn get_constraint_name(convert_data:&mut ConvertData, constraint:usize) -> String {
+    match constraint {
+        type_utils::CONSTRAINT_COMPARABLE => return "comparable".to_string(),
+        type_utils::CONSTRAINT_ORDERED => {
+            go_helpers::use_helper(convert_data, go_helpers::ORDERED_CONSTRAINT);
+            return go_helpers::ORDERED_CONSTRAINT.to_string();
+        }
+        type_utils::CONSTRAINT_NUMBER => {
+            go_helpers::use_helper(convert_data, go_helpers::NUMBER_CONSTRAINT);
+            return go_helpers::NUMBER_CONSTRAINT.to_string();
+        }
+        type_utils::CONSTRAINT_INTEGER => {
+            go_helpers::use_helper(convert_data, go_helpers::INTEGER_CONSTRAINT);
+            return go_helpers::INTEGER_CONSTRAINT.to_string();
+        }
+        _ => return "any".to_string(),
+    }
+}
+
+/// "[int32, string]" for a generic call's type arguments
+pub fn write_type_arguments(convert_data:&mut ConvertData, node:&ASTNode) {
+    if node.middle.is_null() {
+        return;
+    }
+    unsafe {
+        let type_arguments_node:&ASTNode = &*node.middle;
+        let argument_count:usize = type_arguments_node.children.len();
+
+        convert_data.code_builder.append("[");
+        for i in 0..argument_count {
+            if i != 0 {
+                convert_data.code_builder.append(", ");
+            }
+            let type_text:String = type_utils::type_node_ref_to_string(&type_arguments_node.children[i]);
+            go_utils::use_type_imports(convert_data, &type_text);
+            convert_data.code_builder.append_fmt(go_utils::type_text_to_go_type(&type_text));
+        }
+        convert_data.code_builder.append("]");
+    }
+}
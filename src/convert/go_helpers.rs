@@ -0,0 +1,125 @@
+use crate::convert::go_imports;
+use crate::core::structs;
+use structs::ConvertData;
+
+// Constraints for generic type parameters, each one a subset of the one before
+pub const ORDERED_CONSTRAINT:&str = "__Ordered";
+pub const NUMBER_CONSTRAINT:&str = "__Number";
+pub const INTEGER_CONSTRAINT:&str = "__Integer";
+
+// Integer versions of math functions Go only has for float64
+pub const ABS_HELPER:&str = "__abs";
+pub const MIN_HELPER:&str = "__min";
+pub const MAX_HELPER:&str = "__max";
+
+// parse_i32 gives 0 for text that isn't a number
+pub const PARSE_I32_HELPER:&str = "__parse_i32";
+
+// Console input, every read shares the one scanner so no input is lost between them
+pub const STDIN_SCANNER:&str = "__stdin_scanner";
+pub const READLN_HELPER:&str = "__readln";
+pub const READ_I32_HELPER:&str = "__read_i32";
+pub const READ_F64_HELPER:&str = "__read_f64";
+
+// Files
+pub const READ_FILE_HELPER:&str = "__read_file";
+pub const READ_LINES_HELPER:&str = "__read_lines";
+pub const WRITE_FILE_HELPER:&str = "__write_file";
+pub const APPEND_FILE_HELPER:&str = "__append_file";
+
+// assert
+pub const ASSERT_HELPER:&str = "__assert";
+
+const INTEGER_TYPES:&str = "~int | ~int8 | ~int16 | ~int32 | ~int64 | ~uint | ~uint8 | ~uint16 | ~uint32 | ~uint64 | ~uintptr";
+const FLOAT_TYPES:&str = "~float32 | ~float64";
+
+/// Adds a helper along with the helpers and Go packages it needs
+pub fn use_helper(convert_data:&mut ConvertData, name:&str) {
+    match name {
+        ABS_HELPER => use_helper(convert_data, NUMBER_CONSTRAINT),
+        MIN_HELPER | MAX_HELPER => use_helper(convert_data, ORDERED_CONSTRAINT),
+        PARSE_I32_HELPER => convert_data.add_import(go_imports::STRCONV),
+        STDIN_SCANNER => {
+            convert_data.add_import(go_imports::BUFIO);
+            convert_data.add_import(go_imports::OS);
+        }
+        READLN_HELPER => use_helper(convert_data, STDIN_SCANNER),
+        READ_I32_HELPER | READ_F64_HELPER => {
+            use_helper(convert_data, READLN_HELPER);
+            convert_data.add_import(go_imports::STRCONV);
+            convert_data.add_import(go_imports::STRINGS);
+        }
+        READ_FILE_HELPER | WRITE_FILE_HELPER | APPEND_FILE_HELPER => convert_data.add_import(go_imports::OS),
+        READ_LINES_HELPER => {
+            convert_data.add_import(go_imports::BUFIO);
+            convert_data.add_import(go_imports::OS);
+        }
+        _ => {}
+    }
+    convert_data.add_helper(name);
+}
+
+/// Writes every helper added with convert_data.add_helper, Go doesn't mind them coming last
+pub fn write_helpers(convert_data:&mut ConvertData) {
+
+    let helper_count:usize = convert_data.helpers.len();
+    for i in 0..helper_count {
+        let name:String = convert_data.helpers[i].clone();
+        let helper_code:String = get_helper_code(&name);
+        convert_data.code_builder.append_fmt(helper_code);
+    }
+}
+
+fn get_helper_code(name:&str) -> String {
+    match name {
+        ORDERED_CONSTRAINT => {
+            return format!("type {} interface {{\n\t{} | {} | ~string\n}}\n\n", ORDERED_CONSTRAINT, INTEGER_TYPES, FLOAT_TYPES);
+        }
+        NUMBER_CONSTRAINT => {
+            return format!("type {} interface {{\n\t{} | {}\n}}\n\n", NUMBER_CONSTRAINT, INTEGER_TYPES, FLOAT_TYPES);
+        }
+        INTEGER_CONSTRAINT => {
+            return format!("type {} interface {{\n\t{}\n}}\n\n", INTEGER_CONSTRAINT, INTEGER_TYPES);
+        }
+        ABS_HELPER => {
+            return format!("func {}[T {}](x T) T {{\n\tif x < 0 {{\n\t\treturn -x\n\t}}\n\treturn x\n}}\n\n", ABS_HELPER, NUMBER_CONSTRAINT);
+        }
+        MIN_HELPER => {
+            return format!("func {}[T {}](a T, b T) T {{\n\tif a < b {{\n\t\treturn a\n\t}}\n\treturn b\n}}\n\n", MIN_HELPER, ORDERED_CONSTRAINT);
+        }
+        MAX_HELPER => {
+            return format!("func {}[T {}](a T, b T) T {{\n\tif a > b {{\n\t\treturn a\n\t}}\n\treturn b\n}}\n\n", MAX_HELPER, ORDERED_CONSTRAINT);
+        }
+        PARSE_I32_HELPER => {
+            return format!("func {}(text string) int32 {{\n\tvalue, err := strconv.ParseInt(text, 10, 32)\n\tif err != nil {{\n\t\treturn 0\n\t}}\n\treturn int32(value)\n}}\n\n", PARSE_I32_HELPER);
+        }
+        STDIN_SCANNER => {
+            return format!("var {} = bufio.NewScanner(os.Stdin)\n\n", STDIN_SCANNER);
+        }
+        READLN_HELPER => {
+            return format!("func {}() string {{\n\tif {}.Scan() {{\n\t\treturn {}.Text()\n\t}}\n\treturn \"\"\n}}\n\n", READLN_HELPER, STDIN_SCANNER, STDIN_SCANNER);
+        }
+        READ_I32_HELPER => {
+            return format!("func {}() (int32, bool) {{\n\tvalue, err := strconv.ParseInt(strings.TrimSpace({}()), 10, 32)\n\tif err != nil {{\n\t\treturn 0, false\n\t}}\n\treturn int32(value), true\n}}\n\n", READ_I32_HELPER, READLN_HELPER);
+        }
+        READ_F64_HELPER => {
+            return format!("func {}() (float64, bool) {{\n\tvalue, err := strconv.ParseFloat(strings.TrimSpace({}()), 64)\n\tif err != nil {{\n\t\treturn 0, false\n\t}}\n\treturn value, true\n}}\n\n", READ_F64_HELPER, READLN_HELPER);
+        }
+        READ_FILE_HELPER => {
+            return format!("func {}(path string) (string, bool) {{\n\tcontents, err := os.ReadFile(path)\n\tif err != nil {{\n\t\treturn \"\", false\n\t}}\n\treturn string(contents), true\n}}\n\n", READ_FILE_HELPER);
+        }
+        READ_LINES_HELPER => {
+            return format!("func {}(path string) ([]string, bool) {{\n\tfile, err := os.Open(path)\n\tif err != nil {{\n\t\treturn nil, false\n\t}}\n\tdefer file.Close()\n\n\tvar lines []string\n\tscanner := bufio.NewScanner(file)\n\tfor scanner.Scan() {{\n\t\tlines = append(lines, scanner.Text())\n\t}}\n\treturn lines, scanner.Err() == nil\n}}\n\n", READ_LINES_HELPER);
+        }
+        WRITE_FILE_HELPER => {
+            return format!("func {}(path string, contents string) bool {{\n\treturn os.WriteFile(path, []byte(contents), 0644) == nil\n}}\n\n", WRITE_FILE_HELPER);
+        }
+        APPEND_FILE_HELPER => {
+            return format!("func {}(path string, contents string) bool {{\n\tfile, err := os.OpenFile(path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0644)\n\tif err != nil {{\n\t\treturn false\n\t}}\n\t_, err = file.WriteString(contents)\n\tcloseErr := file.Close()\n\treturn err == nil && closeErr == nil\n}}\n\n", APPEND_FILE_HELPER);
+        }
+        ASSERT_HELPER => {
+            return format!("func {}(condition bool, message string) {{\n\tif !condition {{\n\t\tpanic(message)\n\t}}\n}}\n\n", ASSERT_HELPER);
+        }
+        _ => return String::new(),
+    }
+}
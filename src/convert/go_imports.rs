@@ -0,0 +1,33 @@
+use crate::core::structs;
+use structs::StringBuilder;
+
+// Go packages the generated code can use, added with convert_data.add_import
+pub const BUFIO:&str = "bufio";
+pub const FMT:&str = "fmt";
+pub const MATH:&str = "math";
+pub const OS:&str = "os";
+pub const STRCONV:&str = "strconv";
+pub const STRINGS:&str = "strings";
+pub const SYNC:&str = "sync";
+
+/// Writes the import block sorted like gofmt does, nothing when no package is used
+pub fn write_imports(file_builder:&mut StringBuilder, go_imports:&Vec<String>) {
+
+    let mut sorted_imports:Vec<String> = go_imports.clone();
+    sorted_imports.sort();
+
+    let import_count:usize = sorted_imports.len();
+    if import_count == 0 {
+        return;
+    }
+    if import_count == 1 {
+        file_builder.append_fmt(format!("import \"{}\"\n\n", sorted_imports[0]));
+        return;
+    }
+
+    file_builder.append("import (\n");
+    for i in 0..import_count {
+        file_builder.append_fmt(format!("\t\"{}\"\n", sorted_imports[i]));
+    }
+    file_builder.append(")\n\n");
+}
@@ -1,49 +1,62 @@
-use crate::core::enums::ASTNodeType;
-use crate::core::structs;
-use structs::ASTNode;
-use structs::ConvertData;
-use structs::Token;
-
-pub fn process_print(convert_data:&mut ConvertData, node:ASTNode, new_line:bool) {
-    //Children = nodes to print
-    //node type - print or println
-    //token - print token for debugging
-
-    convert_data.code_builder.append("\t");
-
-    let child_count:usize = node.children.len();
-
-    if child_count == 0 {
-        if new_line == true {
-            convert_data.code_builder.append("fmt.println()\n\t");
-            convert_data.code_builder.append("\n");
-        }
-        return;
-    }
-
-    if new_line {
-        convert_data.code_builder.append("fmt.Println(");
-    } else {
-        convert_data.code_builder.append("fmt.Print(");
-    }
-
-    for i in 0..child_count {
-        unsafe {
-            let child:&ASTNode = &node.children[i];
-            let token_ptr:*mut Token = child.token;
-            let token:Token = (*token_ptr).clone();
-
-            if i != 0 {
-                convert_data.code_builder.append(", ");
-            }
-            if child.node_type == ASTNodeType::StringLiteral {
-                convert_data.code_builder.append("\"");
-            }
-            convert_data.code_builder.append(token.text.as_str());
-            if child.node_type == ASTNodeType::StringLiteral {
-                convert_data.code_builder.append("\"");
-            }
-        }
-    }
-    convert_data.code_builder.append(")");
-}
+use crate::convert::go_imports;
+use crate::convert::go_print_expressions;
+use crate::convert::go_ternary;
+use crate::core::structs;
+use crate::core::type_utils;
+use structs::ASTNode;
+use structs::ConvertData;
+
+pub fn process_print(convert_data:&mut ConvertData, node:ASTNode, new_line:bool) {
+    //Children = nodes to print
+    //node type - print or println
+    //token - print token for debugging
+
+    let child_count:usize = node.children.len();
+
+    for i in 0..child_count {
+        go_ternary::lower_ternaries(convert_data, &node.children[i], "");
+        if convert_data.is_error() {
+            return;
+        }
+    }
+
+    convert_data.append_indentation();
+
+    if child_count == 0 {
+        if new_line == true {
+            convert_data.add_import(go_imports::FMT);
+            convert_data.code_builder.append("fmt.Println()\n");
+        }
+        return;
+    }
+
+    convert_data.add_import(go_imports::FMT);
+
+    if new_line {
+        convert_data.code_builder.append("fmt.Println(");
+    } else {
+        convert_data.code_builder.append("fmt.Print(");
+    }
+
+    for i in 0..child_count {
+        let child:&ASTNode = &node.children[i];
+
+        if i != 0 {
+            convert_data.code_builder.append(", ");
+        }
+
+        //a Go byte prints as a number, a char should print as its letter
+        let child_type:String = type_utils::get_expression_type(child, &convert_data.symbol_table);
+        if child_type == type_utils::CHAR_TYPE {
+            convert_data.code_builder.append("string(rune(");
+            go_print_expressions::print_expression(convert_data, child);
+            convert_data.code_builder.append("))");
+        } else {
+            go_print_expressions::print_expression(convert_data, child);
+        }
+        if convert_data.is_error() {
+            return;
+        }
+    }
+    convert_data.code_builder.append(")\n");
+}
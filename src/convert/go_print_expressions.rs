@@ -0,0 +1,295 @@
+use crate::convert::go_builtins;
+use crate::convert::go_generics;
+use crate::convert::go_utils;
+use crate::core::builtins;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::Token;
+
+pub fn print_expression(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    convert_data.error_function = "printExpression".to_string();
+
+    if node.token.is_null() {
+        convert_data.error_detail = format!("{} node has no token", enums::astnodetype_to_string(node.node_type));
+        convert_data.error_code = ConvertError::NodeIsNull;
+        return;
+    }
+
+    unsafe {
+        let token:Token = (*node.token).clone();
+
+        match node.node_type {
+            ASTNodeType::Identifier => {
+                if builtins::is_builtin_constant_node(node, &convert_data.symbol_table) {
+                    go_builtins::print_builtin_constant(convert_data, &token.text);
+                    return;
+                }
+                convert_data.code_builder.append(token.text.as_str());
+                return;
+            }
+            ASTNodeType::IntegerLiteral | ASTNodeType::FloatLiteral | ASTNodeType::BoolLiteral => {
+                convert_data.code_builder.append(token.text.as_str());
+                return;
+            }
+            ASTNodeType::StringLiteral => {
+                convert_data.code_builder.append_fmt(format!("\"{}\"", token.text));
+                return;
+            }
+            ASTNodeType::CharLiteral => {
+                convert_data.code_builder.append_fmt(format!("'{}'", token.text));
+                return;
+            }
+            ASTNodeType::Minus => {
+                print_minus(convert_data, node);
+                return;
+            }
+            ASTNodeType::Cast => {
+                print_cast(convert_data, node);
+                return;
+            }
+            ASTNodeType::FunctionCall => {
+                print_function_call(convert_data, node);
+                return;
+            }
+            ASTNodeType::FieldAccess => {
+                if node.left.is_null() {
+                    convert_data.error_detail = "node.left is null in field access".to_string();
+                    convert_data.error_code = ConvertError::NodeIsNull;
+                    return;
+                }
+                print_expression(convert_data, &*node.left);
+                convert_data.code_builder.append_fmt(format!(".{}", token.text));
+                return;
+            }
+            ASTNodeType::ArrayAccess => {
+                print_array_access(convert_data, node);
+                return;
+            }
+            ASTNodeType::Ternary => {
+                //written out before the statement by go_ternary
+                let temp_name:String = convert_data.get_temp_var(node as *const ASTNode);
+                if temp_name.is_empty() {
+                    convert_data.error_token = token;
+                    convert_data.error_detail = "ternary was not lowered before its statement".to_string();
+                    convert_data.error_code = ConvertError::UnimplementedNodeType;
+                    return;
+                }
+                convert_data.code_builder.append(temp_name.as_str());
+                return;
+            }
+            _ => {}
+        }
+
+        if type_utils::is_binary_node(node) {
+            //a && or || with a ternary on its right was written out before the statement by go_ternary
+            let temp_name:String = convert_data.get_temp_var(node as *const ASTNode);
+            if temp_name.is_empty() == false {
+                convert_data.code_builder.append(temp_name.as_str());
+                return;
+            }
+            print_binary_expression(convert_data, node);
+            return;
+        }
+
+        convert_data.error_token = token;
+        convert_data.error_detail = format!("{} not implemented in expressions yet", enums::astnodetype_to_string(node.node_type));
+        convert_data.error_code = ConvertError::UnimplementedNodeType;
+    }
+}
+
+fn print_function_call(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    //Node_type: FunctionCall
+    //Token:     function name
+    //Left:      the function being called, a name or a function value
+    //Middle:    TypeArguments for a generic call, otherwise null
+    //Children:  arguments
+
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "node.left is null in function call".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let function_node:&ASTNode = &*node.left;
+
+        if builtins::is_builtin_call(node, &convert_data.symbol_table) {
+            go_builtins::print_builtin_call(convert_data, node);
+            return;
+        }
+
+        print_expression(convert_data, function_node);
+        if convert_data.is_error() {
+            return;
+        }
+        go_generics::write_type_arguments(convert_data, node);
+        convert_data.code_builder.append("(");
+
+        let argument_count:usize = node.children.len();
+        for i in 0..argument_count {
+            if i != 0 {
+                convert_data.code_builder.append(", ");
+            }
+            print_expression(convert_data, &node.children[i]);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+        convert_data.code_builder.append(")");
+    }
+}
+
+fn print_array_access(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    //Node_type: ArrayAccess
+    //Token:     '['
+    //Left:      string or array being indexed
+    //Right:     index
+
+    unsafe {
+        if node.left.is_null() || node.right.is_null() {
+            convert_data.error_detail = "index is missing its value or index".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let value_node:&ASTNode = &*node.left;
+        let index_node:&ASTNode = &*node.right;
+
+        print_indexed_value(convert_data, value_node);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append("[");
+        print_expression(convert_data, index_node);
+        convert_data.code_builder.append("]");
+    }
+}
+
+/// The value before a [ ], bracketed when it is an expression
+pub fn print_indexed_value(convert_data:&mut ConvertData, node:&ASTNode) {
+    let needs_brackets:bool = type_utils::is_binary_node(node) || node.node_type == ASTNodeType::Minus;
+    if needs_brackets {
+        convert_data.code_builder.append("(");
+    }
+    print_expression(convert_data, node);
+    if needs_brackets {
+        convert_data.code_builder.append(")");
+    }
+}
+
+fn print_minus(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        if node.left.is_null() {
+            convert_data.error_detail = "node.left is null in minus".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let value_node:&ASTNode = &*node.left;
+
+        convert_data.code_builder.append("-");
+        if type_utils::is_binary_node(value_node) {
+            convert_data.code_builder.append("(");
+            print_expression(convert_data, value_node);
+            convert_data.code_builder.append(")");
+            return;
+        }
+        print_expression(convert_data, value_node);
+    }
+}
+
+fn print_cast(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    //Node_type: Cast
+    //Token:     type token
+    //Left:      type node
+    //Right:     value being cast
+
+    unsafe {
+        if node.left.is_null() || node.right.is_null() {
+            convert_data.error_detail = "cast is missing its type or value".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let type_token:Token = (*node.token).clone();
+        let value_node:&ASTNode = &*node.right;
+
+        let target_type:String = type_utils::type_node_to_string(node.left);
+        let value_type:String = type_utils::get_expression_type(value_node, &convert_data.symbol_table);
+
+        if type_utils::is_castable_type_text(&target_type) == false {
+            convert_data.error_token = type_token;
+            convert_data.error_detail = format!("cannot cast to '{}', only number and char types can be cast", target_type);
+            convert_data.error_code = ConvertError::InvalidCast;
+            return;
+        }
+        let value_type_known:bool = value_type.is_empty() == false;
+        if value_type_known && type_utils::is_castable_type_text(&value_type) == false {
+            convert_data.error_token = type_token;
+            convert_data.error_detail = format!("cannot cast '{}' to '{}', only number and char types can be cast", value_type, target_type);
+            convert_data.error_code = ConvertError::InvalidCast;
+            return;
+        }
+
+        let go_type:String = go_utils::type_node_to_go_type(node.left);
+        convert_data.code_builder.append_fmt(format!("{}(", go_type));
+        print_expression(convert_data, value_node);
+        convert_data.code_builder.append(")");
+    }
+}
+
+fn print_binary_expression(convert_data:&mut ConvertData, node:&ASTNode) {
+
+    //Token: operator
+    //Left:  left side
+    //Right: right side
+
+    unsafe {
+        let operator_token:Token = (*node.token).clone();
+        let left_node:&ASTNode = &*node.left;
+        let right_node:&ASTNode = &*node.right;
+
+        let precedence:usize = token_utils::get_precedence_bool(operator_token.token_type);
+
+        print_operand(convert_data, left_node, precedence, false);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append_fmt(format!(" {} ", operator_token.text));
+        print_operand(convert_data, right_node, precedence, true);
+    }
+}
+
+/// Brackets are only added back where the tree's shape needs them
+fn print_operand(convert_data:&mut ConvertData, node:&ASTNode, parent_precedence:usize, is_right:bool) {
+
+    if type_utils::is_binary_node(node) == false {
+        print_expression(convert_data, node);
+        return;
+    }
+
+    let precedence:usize;
+    unsafe {
+        precedence = token_utils::get_precedence_bool((*node.token).token_type);
+    }
+
+    let mut needs_brackets:bool = precedence < parent_precedence;
+    if is_right && precedence == parent_precedence {
+        needs_brackets = true;
+    }
+
+    if needs_brackets {
+        convert_data.code_builder.append("(");
+    }
+    print_expression(convert_data, node);
+    if needs_brackets {
+        convert_data.code_builder.append(")");
+    }
+}
@@ -0,0 +1,41 @@
+use crate::convert::go_utils;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::Token;
+
+pub fn process_struct_declaration(convert_data:&mut ConvertData, node:ASTNode) {
+
+    convert_data.error_function = "processStructDeclaration".to_string();
+
+    //Node_type: StructDeclaration
+    //Token:     struct name
+    //Children:  StructField nodes, token is the field name, left is its type
+
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+
+        convert_data.code_builder.append_fmt(format!("type {} struct {{\n", name_token.text));
+
+        let field_count:usize = node.children.len();
+        for i in 0..field_count {
+            let field:&ASTNode = &node.children[i];
+            if field.token.is_null() || field.left.is_null() {
+                convert_data.error_token = name_token;
+                convert_data.error_detail = "struct field is missing its name or type".to_string();
+                convert_data.error_code = ConvertError::NodeIsNull;
+                return;
+            }
+            let field_name:String = (*field.token).text.clone();
+            let field_type:String = type_utils::type_node_to_string(field.left);
+            go_utils::use_type_imports(convert_data, &field_type);
+            let go_type:String = go_utils::type_node_to_go_type(field.left);
+            convert_data.code_builder.append_fmt(format!("\t{} {}\n", field_name, go_type));
+        }
+
+        convert_data.code_builder.append("}\n\n");
+    }
+}
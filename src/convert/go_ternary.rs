@@ -0,0 +1,279 @@
+use crate::convert::go_print_expressions;
+use crate::convert::go_utils;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::ConvertError;
+use structs::ASTNode;
+use structs::ConvertData;
+use structs::Token;
+
+/// Go has no ternary, so each one is written before the statement as a temp var set in an if/else.
+/// A ternary on the right of && or || takes the whole expression with it, see lower_short_circuit.
+/// expected_type is the type the expression is going into, used when both arms are literals.
+pub fn lower_ternaries(convert_data:&mut ConvertData, node:&ASTNode, expected_type:&str) {
+
+    convert_data.error_function = "lowerTernaries".to_string();
+
+    if node.node_type == ASTNodeType::Ternary {
+        lower_ternary(convert_data, node, expected_type);
+        return;
+    }
+    if is_short_circuit_node(node) {
+        unsafe {
+            if has_ternary(&*node.right) {
+                lower_short_circuit(convert_data, node);
+                return;
+            }
+        }
+    }
+
+    //operands of a binary expression share a type
+    let mut child_expected_type:String = expected_type.to_string();
+    if type_utils::is_binary_node(node) {
+        unsafe {
+            let left_type:String = type_utils::get_expression_type(&*node.left, &convert_data.symbol_table);
+            let right_type:String = type_utils::get_expression_type(&*node.right, &convert_data.symbol_table);
+            let operand_type:String = type_utils::get_operand_type(&left_type, &right_type);
+            if operand_type.is_empty() == false && type_utils::is_literal_type_text(&operand_type) == false {
+                child_expected_type = operand_type;
+            }
+        }
+    }
+
+    unsafe {
+        if node.left.is_null() == false {
+            lower_ternaries(convert_data, &*node.left, &child_expected_type);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+        if node.middle.is_null() == false {
+            lower_ternaries(convert_data, &*node.middle, &child_expected_type);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+        if node.right.is_null() == false {
+            lower_ternaries(convert_data, &*node.right, &child_expected_type);
+            if convert_data.is_error() {
+                return;
+            }
+        }
+    }
+
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        lower_ternaries(convert_data, &node.children[i], "");
+        if convert_data.is_error() {
+            return;
+        }
+    }
+}
+
+/// True if lowering the expression would write anything before its statement
+pub fn has_ternary(node:&ASTNode) -> bool {
+    if node.node_type == ASTNodeType::Ternary {
+        return true;
+    }
+    unsafe {
+        if node.left.is_null() == false && has_ternary(&*node.left) {
+            return true;
+        }
+        if node.middle.is_null() == false && has_ternary(&*node.middle) {
+            return true;
+        }
+        if node.right.is_null() == false && has_ternary(&*node.right) {
+            return true;
+        }
+    }
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        if has_ternary(&node.children[i]) {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn is_short_circuit_node(node:&ASTNode) -> bool {
+    if type_utils::is_binary_node(node) == false {
+        return false;
+    }
+    unsafe {
+        let token_type:i32 = (*node.token).token_type;
+        return token_type == TokenType::AndAnd || token_type == TokenType::OrOr;
+    }
+}
+
+// The right side of && and || only runs when the left doesn't decide the result,
+// so a ternary in it can't go before the statement. The whole expression becomes
+//  var __condition_0 bool = left
+//  if __condition_0 {          if !__condition_0 { for ||
+//      __condition_0 = right
+//  }
+fn lower_short_circuit(convert_data:&mut ConvertData, node:&ASTNode) {
+    unsafe {
+        let left_node:&ASTNode = &*node.left;
+        let right_node:&ASTNode = &*node.right;
+        let is_and:bool = (*node.token).token_type == TokenType::AndAnd;
+
+        lower_ternaries(convert_data, left_node, type_utils::BOOL_TYPE);
+        if convert_data.is_error() {
+            return;
+        }
+
+        let temp_name:String = convert_data.create_temp_var(node as *const ASTNode, "condition");
+        convert_data.append_indentation();
+        convert_data.code_builder.append_fmt(format!("var {} bool = ", temp_name));
+        go_print_expressions::print_expression(convert_data, left_node);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append("\n");
+
+        convert_data.append_indentation();
+        if is_and {
+            convert_data.code_builder.append_fmt(format!("if {} {{\n", temp_name));
+        } else {
+            convert_data.code_builder.append_fmt(format!("if !{} {{\n", temp_name));
+        }
+        convert_data.increment_index_count();
+        lower_ternaries(convert_data, right_node, type_utils::BOOL_TYPE);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.append_indentation();
+        convert_data.code_builder.append_fmt(format!("{} = ", temp_name));
+        go_print_expressions::print_expression(convert_data, right_node);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append("\n");
+        convert_data.decrement_index_count();
+
+        convert_data.append_indentation();
+        convert_data.code_builder.append("}\n");
+    }
+}
+
+fn lower_ternary(convert_data:&mut ConvertData, node:&ASTNode, expected_type:&str) {
+
+    //Node_type: Ternary
+    //Token:     '?'
+    //Left:      condition
+    //Middle:    value if true
+    //Right:     value if false
+
+    unsafe {
+        if node.left.is_null() || node.middle.is_null() || node.right.is_null() {
+            convert_data.error_detail = "ternary is missing a part".to_string();
+            convert_data.error_code = ConvertError::NodeIsNull;
+            return;
+        }
+        let question_token:Token = (*node.token).clone();
+        let condition_node:&ASTNode = &*node.left;
+        let true_node:&ASTNode = &*node.middle;
+        let false_node:&ASTNode = &*node.right;
+
+        let true_type:String = type_utils::get_expression_type(true_node, &convert_data.symbol_table);
+        let false_type:String = type_utils::get_expression_type(false_node, &convert_data.symbol_table);
+        //semantic has checked both types, the temporary can't be declared without one
+        if true_type.is_empty() || false_type.is_empty() {
+            convert_data.error_token = question_token;
+            convert_data.error_detail = "ternary values have no type to declare the temporary with".to_string();
+            convert_data.error_code = ConvertError::InvalidNodeType;
+            return;
+        }
+
+        let mut ternary_type:String = type_utils::get_operand_type(&true_type, &false_type);
+        if type_utils::is_literal_type_text(&ternary_type) {
+            ternary_type = get_literal_ternary_type(&ternary_type, expected_type);
+        }
+
+        //the condition always runs, only the chosen arm does
+        lower_ternaries(convert_data, condition_node, type_utils::BOOL_TYPE);
+        if convert_data.is_error() {
+            return;
+        }
+
+        let temp_name:String = convert_data.create_temp_var(node as *const ASTNode, "ternary");
+        let go_type:String = go_utils::type_text_to_go_type(&ternary_type);
+        go_utils::use_type_imports(convert_data, &ternary_type);
+
+        convert_data.append_indentation();
+        convert_data.code_builder.append_fmt(format!("var {} {}\n", temp_name, go_type));
+        convert_data.append_indentation();
+        convert_data.code_builder.append("if ");
+        go_print_expressions::print_expression(convert_data, condition_node);
+        if convert_data.is_error() {
+            return;
+        }
+        convert_data.code_builder.append(" {\n");
+
+        write_ternary_arm(convert_data, true_node, &temp_name, &ternary_type);
+        if convert_data.is_error() {
+            return;
+        }
+
+        convert_data.append_indentation();
+        convert_data.code_builder.append("} else {\n");
+
+        write_ternary_arm(convert_data, false_node, &temp_name, &ternary_type);
+        if convert_data.is_error() {
+            return;
+        }
+
+        convert_data.append_indentation();
+        convert_data.code_builder.append("}\n");
+    }
+}
+
+fn write_ternary_arm(convert_data:&mut ConvertData, node:&ASTNode, temp_name:&str, ternary_type:&str) {
+    convert_data.increment_index_count();
+
+    lower_ternaries(convert_data, node, ternary_type);
+    if convert_data.is_error() {
+        return;
+    }
+    convert_data.append_indentation();
+    convert_data.code_builder.append_fmt(format!("{} = ", temp_name));
+    go_print_expressions::print_expression(convert_data, node);
+    convert_data.code_builder.append("\n");
+
+    convert_data.decrement_index_count();
+}
+
+fn get_literal_ternary_type(literal_type:&str, expected_type:&str) -> String {
+    let expected_is_typed:bool = expected_type.is_empty() == false && type_utils::is_literal_type_text(expected_type) == false;
+    if expected_is_typed && type_utils::types_match(expected_type, literal_type) {
+        return expected_type.to_string();
+    }
+    if literal_type == type_utils::FLOAT_LITERAL_TYPE {
+        return "f64".to_string();
+    }
+    return "i32".to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    #[test]
+    fn ternary_becomes_if_else() {
+        let code:String = "fn void main() {\n    i32 x = 1;\n    i32 y = x > 0 ? 1 : 2;\n    println(y);\n}\n".to_string();
+        let go_code:String = test_utils::convert_code(&code);
+        assert!(go_code.contains("\tvar __ternary_0 int32\n\tif x > 0 {\n\t\t__ternary_0 = 1\n\t} else {\n\t\t__ternary_0 = 2\n\t}\n\tvar y int32 = __ternary_0\n"), "{}", go_code);
+    }
+
+    #[test]
+    fn ternary_right_of_and_only_runs_when_the_left_is_true() {
+        let code:String = "fn void main() {\n    i32 x = 1;\n    bool b = x > 0 && (x > 1 ? true : false);\n    println(b);\n}\n".to_string();
+        let go_code:String = test_utils::convert_code(&code);
+        assert!(go_code.contains("\tvar __condition_0 bool = x > 0\n\tif __condition_0 {\n\t\tvar __ternary_1 bool\n"), "{}", go_code);
+        assert!(go_code.contains("\t\t__condition_0 = __ternary_1\n\t}\n\tvar b bool = __condition_0\n"), "{}", go_code);
+    }
+}
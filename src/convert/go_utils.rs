@@ -1,37 +1,154 @@
-
-use crate::core::structs;
-use structs::Token;
-use crate::core::enums;
-use enums::TokenType;
-
-pub fn convert_to_go_type(token:Token) -> &'static str {
-    match token.token_type {
-            TokenType::i8 | TokenType::u8 => return "int8",
-            TokenType::i16 | TokenType::u16 => return "int16",
-            TokenType::Int | TokenType::i32 | TokenType::u32 => return "int",
-            TokenType::i64 | TokenType::u64 | TokenType::Usize => return "int64",
-            TokenType::f32 => return "float32",
-            TokenType::f64 => return "float64",
-            TokenType::String => return "string",
-            TokenType::Char => return "byte",
-            TokenType::Bool => return "bool",
-            TokenType::Void => return "",
-            _ => return "Unknown",
-    }
-}
-
-pub fn convert_type_to_go_type(token_type:i32) -> &'static str {
-    match token_type {
-            TokenType::i8 | TokenType::u8 => return "int8",
-            TokenType::i16 | TokenType::u16 => return "int16",
-            TokenType::Int | TokenType::i32 | TokenType::u32 => return "int",
-            TokenType::i64 | TokenType::u64 | TokenType::Usize => return "int64",
-            TokenType::f32 => return "float32",
-            TokenType::f64 => return "float64",
-            TokenType::String => return "string",
-            TokenType::Char => return "byte",
-            TokenType::Bool => return "bool",
-            TokenType::Void => return "",
-            _ => return "Unknown",
-    }
-}
\ No newline at end of file
+use crate::convert::go_imports;
+use crate::core::enums;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::core::type_utils;
+use crate::parse::parse_utils;
+use enums::ASTNodeType;
+use enums::TokenType;
+use structs::ASTNode;
+use structs::ConvertData;
+
+// The one source type -> Go type table, every width and signedness is kept
+pub fn convert_type_to_go_type(token_type:i32) -> &'static str {
+    match token_type {
+            TokenType::i8 => return "int8",
+            TokenType::u8 => return "uint8",
+            TokenType::i16 => return "int16",
+            TokenType::u16 => return "uint16",
+            TokenType::Int | TokenType::i32 => return "int32",
+            TokenType::u32 => return "uint32",
+            TokenType::i64 => return "int64",
+            TokenType::u64 => return "uint64",
+            TokenType::Usize => return "uint",
+            TokenType::f32 => return "float32",
+            TokenType::f64 => return "float64",
+            TokenType::String => return "string",
+            TokenType::Char => return "byte",
+            TokenType::Bool => return "bool",
+            TokenType::Void => return "",
+            _ => return "Unknown",
+    }
+}
+
+/// Converts a whole type node chain, e.g. Pointer -> VarType(i32) becomes "*int32"
+pub fn type_node_to_go_type(node_ptr:*mut ASTNode) -> String {
+    unsafe {
+        if node_ptr.is_null() {
+            return "Unknown".to_string();
+        }
+        let node:&ASTNode = &*node_ptr;
+
+        if node.node_type == ASTNodeType::Pointer {
+            let inner_type:String = type_node_to_go_type(node.left);
+            return format!("*{}", inner_type);
+        }
+        if node.node_type == ASTNodeType::Array {
+            let element_type:String = type_node_to_go_type(node.left);
+            return format!("[]{}", element_type);
+        }
+        if node.node_type == ASTNodeType::FunctionType {
+            let type_text:String = type_utils::type_node_ref_to_string(node);
+            return type_text_to_go_type(&type_text);
+        }
+        if node.node_type == ASTNodeType::Channel {
+            let element_type:String = type_node_to_go_type(node.left);
+            return format!("chan {}", element_type);
+        }
+        if node.token.is_null() {
+            return "Unknown".to_string();
+        }
+
+        let token_type:i32 = (*node.token).token_type;
+        if token_type == TokenType::Identifier {
+            let sync_type:&str = get_sync_go_type(&(*node.token).text);
+            if sync_type.is_empty() == false {
+                return sync_type.to_string();
+            }
+            //user defined type, keeps its name
+            return (*node.token).text.clone();
+        }
+        return convert_type_to_go_type(token_type).to_string();
+    }
+}
+
+/// Same as type_node_to_go_type but from a source type's text, e.g. "*u8" becomes "*uint8"
+pub fn type_text_to_go_type(type_text:&str) -> String {
+    if type_utils::is_function_type_text(type_text) {
+        return function_type_text_to_go_type(type_text);
+    }
+    //"*i32[]" is an array of pointers, the suffix is outermost
+    if type_utils::is_array_type_text(type_text) {
+        let element_type:String = type_text_to_go_type(&type_utils::get_array_element_type(type_text));
+        return format!("[]{}", element_type);
+    }
+    if type_utils::is_channel_type_text(type_text) {
+        let element_type:String = type_text_to_go_type(&type_utils::get_channel_element_type(type_text));
+        return format!("chan {}", element_type);
+    }
+    if type_text.starts_with('*') {
+        let inner_type:String = type_text_to_go_type(&type_text[1..]);
+        return format!("*{}", inner_type);
+    }
+    let token_type:i32 = parse_utils::get_token_type(&type_text.to_string());
+    if token_utils::is_var_type(token_type) {
+        return convert_type_to_go_type(token_type).to_string();
+    }
+    let sync_type:&str = get_sync_go_type(type_text);
+    if sync_type.is_empty() == false {
+        return sync_type.to_string();
+    }
+    //user defined type, keeps its name
+    return type_text.to_string();
+}
+
+// Pointers so copies share the one WaitGroup or Mutex, like channels do
+fn get_sync_go_type(type_text:&str) -> &'static str {
+    match type_text {
+        type_utils::WAIT_GROUP_TYPE => return "*sync.WaitGroup",
+        type_utils::MUTEX_TYPE => return "*sync.Mutex",
+        _ => return "",
+    }
+}
+
+/// Records the Go packages a type written out in the code needs
+pub fn use_type_imports(convert_data:&mut ConvertData, type_text:&str) {
+    if type_utils::type_text_uses_name(type_text, type_utils::WAIT_GROUP_TYPE)
+        || type_utils::type_text_uses_name(type_text, type_utils::MUTEX_TYPE) {
+        convert_data.add_import(go_imports::SYNC);
+    }
+}
+
+/// What a declaration without a value starts as, empty when Go's zero value is usable
+pub fn get_initial_go_value(type_text:&str) -> String {
+    if type_utils::is_channel_type_text(type_text) {
+        return format!("make({})", type_text_to_go_type(type_text));
+    }
+    match type_text {
+        type_utils::WAIT_GROUP_TYPE => return "&sync.WaitGroup{}".to_string(),
+        type_utils::MUTEX_TYPE => return "&sync.Mutex{}".to_string(),
+        _ => return String::new(),
+    }
+}
+
+// "fn(i32, i32) -> i32" becomes "func(int32, int32) int32"
+fn function_type_text_to_go_type(type_text:&str) -> String {
+    let parameter_types:Vec<String> = type_utils::get_function_parameter_types(type_text);
+    let return_type:String = type_utils::get_function_return_type(type_text);
+
+    let mut go_type:String = "func(".to_string();
+    let parameter_count:usize = parameter_types.len();
+    for i in 0..parameter_count {
+        if i != 0 {
+            go_type.push_str(", ");
+        }
+        go_type.push_str(&type_text_to_go_type(&parameter_types[i]));
+    }
+    go_type.push(')');
+
+    if return_type != type_utils::VOID_TYPE {
+        go_type.push(' ');
+        go_type.push_str(&type_text_to_go_type(&return_type));
+    }
+    return go_type;
+}
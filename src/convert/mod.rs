@@ -1,7 +1,15 @@
-
-
-pub mod go_convert;
-pub mod go_functions;
-pub mod go_utils;
-pub mod go_body;
-pub mod go_print;
\ No newline at end of file
+
+
+pub mod go_convert;
+pub mod go_functions;
+pub mod go_utils;
+pub mod go_body;
+pub mod go_print;
+pub mod go_print_expressions;
+pub mod go_declarations;
+pub mod go_ternary;
+pub mod go_structs;
+pub mod go_generics;
+pub mod go_helpers;
+pub mod go_imports;
+pub mod go_builtins;pub mod go_control_flow;
@@ -0,0 +1,306 @@
+use crate::core::enums;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use structs::ASTNode;
+use structs::Symbol;
+use structs::SymbolTable;
+
+// Math functions, floats only in Go so other numbers go through float64
+pub const SQRT: &str = "sqrt";
+pub const POW: &str = "pow";
+pub const FLOOR: &str = "floor";
+pub const CEIL: &str = "ceil";
+pub const SIN: &str = "sin";
+pub const COS: &str = "cos";
+// Keep the type of their arguments
+pub const ABS: &str = "abs";
+pub const MIN: &str = "min";
+pub const MAX: &str = "max";
+
+// String functions
+pub const LEN: &str = "len";
+pub const SUBSTRING: &str = "substring";
+pub const CONTAINS: &str = "contains";
+pub const SPLIT: &str = "split";
+pub const TRIM: &str = "trim";
+pub const TO_STRING: &str = "to_string";
+pub const PARSE_I32: &str = "parse_i32";
+
+// Console input, the numbers come with a flag saying if the line was one
+pub const READLN: &str = "readln";
+pub const READ_I32: &str = "read_i32";
+pub const READ_F64: &str = "read_f64";
+
+// Files, the bool says if the file could be read or written
+pub const READ_FILE: &str = "read_file";
+pub const READ_LINES: &str = "read_lines";
+pub const WRITE_FILE: &str = "write_file";
+pub const APPEND_FILE: &str = "append_file";
+
+// Channels, send gives nothing back and recv waits for a value
+pub const SEND: &str = "send";
+pub const RECV: &str = "recv";
+
+// wait_group and mutex
+pub const ADD: &str = "add";
+pub const DONE: &str = "done";
+pub const WAIT: &str = "wait";
+pub const LOCK: &str = "lock";
+pub const UNLOCK: &str = "unlock";
+
+// Stop the program, the message says where in the source it happened
+pub const ASSERT: &str = "assert";
+pub const PANIC: &str = "panic";
+
+// Constants
+pub const PI: &str = "PI";
+pub const E: &str = "E";
+
+pub const FUNCTION_NAMES: &[&str] = &[
+    SQRT, POW, FLOOR, CEIL, SIN, COS, ABS, MIN, MAX, LEN, SUBSTRING, CONTAINS, SPLIT, TRIM, TO_STRING, PARSE_I32,
+    READLN, READ_I32, READ_F64, READ_FILE, READ_LINES, WRITE_FILE, APPEND_FILE, SEND, RECV,
+    ADD, DONE, WAIT, LOCK, UNLOCK, ASSERT, PANIC,
+];
+
+pub fn is_float_math_function(name: &str) -> bool {
+    return name == SQRT || name == POW || name == FLOOR || name == CEIL || name == SIN || name == COS;
+}
+
+pub fn is_math_function(name: &str) -> bool {
+    return is_float_math_function(name) || name == ABS || name == MIN || name == MAX;
+}
+
+/// Builtins with one fixed signature, written like a function type
+pub fn get_builtin_signature(name: &str) -> &'static str {
+    match name {
+        SUBSTRING => return "fn(string, i32, i32) -> string",
+        CONTAINS => return "fn(string, string) -> bool",
+        SPLIT => return "fn(string, string) -> string[]",
+        TRIM => return "fn(string) -> string",
+        TO_STRING => return "fn(i32) -> string",
+        PARSE_I32 => return "fn(string) -> i32",
+        READLN => return "fn() -> string",
+        READ_I32 => return "fn() -> (i32, bool)",
+        READ_F64 => return "fn() -> (f64, bool)",
+        READ_FILE => return "fn(string) -> (string, bool)",
+        READ_LINES => return "fn(string) -> (string[], bool)",
+        WRITE_FILE => return "fn(string, string) -> bool",
+        APPEND_FILE => return "fn(string, string) -> bool",
+        ADD => return "fn(wait_group, i32)",
+        DONE => return "fn(wait_group)",
+        WAIT => return "fn(wait_group)",
+        LOCK => return "fn(mutex)",
+        UNLOCK => return "fn(mutex)",
+        ASSERT => return "fn(bool, string)",
+        PANIC => return "fn(string)",
+        _ => return "",
+    }
+}
+
+pub fn is_channel_function(name: &str) -> bool {
+    return name == SEND || name == RECV;
+}
+
+pub fn is_builtin_function(name: &str) -> bool {
+    return is_math_function(name) || is_channel_function(name) || name == LEN || get_builtin_signature(name).is_empty() == false;
+}
+
+pub fn is_builtin_constant(name: &str) -> bool {
+    return name == PI || name == E;
+}
+
+pub fn get_builtin_argument_count(name: &str) -> usize {
+    let signature: &str = get_builtin_signature(name);
+    if signature.is_empty() == false {
+        return type_utils::get_function_parameter_types(signature).len();
+    }
+    if name == POW || name == MIN || name == MAX || name == SEND {
+        return 2;
+    }
+    return 1;
+}
+
+/// assert's message can be left out, every other builtin takes a fixed number
+pub fn is_argument_count_valid(name: &str, argument_count: usize) -> bool {
+    if name == ASSERT {
+        return argument_count == 1 || argument_count == 2;
+    }
+    return argument_count == get_builtin_argument_count(name);
+}
+
+/// Anything the program declares itself hides a builtin of the same name
+fn is_unshadowed(name: &str, symbol_table: &SymbolTable) -> bool {
+    let symbol: Symbol = symbol_table.get_symbol(name);
+    return symbol.symbol_kind == SymbolKind::None;
+}
+
+pub fn is_builtin_call(node: &ASTNode, symbol_table: &SymbolTable) -> bool {
+    if node.node_type != ASTNodeType::FunctionCall || node.left.is_null() {
+        return false;
+    }
+    unsafe {
+        let function_node: &ASTNode = &*node.left;
+        if function_node.node_type != ASTNodeType::Identifier || node.middle.is_null() == false {
+            return false;
+        }
+        let name: &str = &(*function_node.token).text;
+        return is_builtin_function(name) && is_unshadowed(name, symbol_table);
+    }
+}
+
+pub fn is_builtin_constant_node(node: &ASTNode, symbol_table: &SymbolTable) -> bool {
+    if node.node_type != ASTNodeType::Identifier || node.token.is_null() {
+        return false;
+    }
+    unsafe {
+        let name: &str = &(*node.token).text;
+        return is_builtin_constant(name) && is_unshadowed(name, symbol_table);
+    }
+}
+
+pub fn get_builtin_constant_type(name: &str) -> String {
+    if is_builtin_constant(name) {
+        return "f64".to_string();
+    }
+    return String::new();
+}
+
+/// A literal on its own takes the same default a ternary does
+pub fn get_default_literal_type(type_text: &str) -> String {
+    if type_text == type_utils::INTEGER_LITERAL_TYPE {
+        return "i32".to_string();
+    }
+    if type_text == type_utils::FLOAT_LITERAL_TYPE {
+        return "f64".to_string();
+    }
+    return type_text.to_string();
+}
+
+pub fn get_argument_types(node: &ASTNode, symbol_table: &SymbolTable) -> Vec<String> {
+    let mut argument_types: Vec<String> = Vec::new();
+    let argument_count: usize = node.children.len();
+    for i in 0..argument_count {
+        argument_types.push(type_utils::get_expression_type(&node.children[i], symbol_table));
+    }
+    return argument_types;
+}
+
+/// The number type every argument shares, a literal takes the type of a typed argument
+pub fn get_shared_argument_type(argument_types: &Vec<String>) -> String {
+    let argument_count: usize = argument_types.len();
+    if argument_count == 0 {
+        return String::new();
+    }
+    let mut shared_type: String = argument_types[0].clone();
+    for i in 1..argument_count {
+        shared_type = type_utils::get_operand_type(&shared_type, &argument_types[i]);
+    }
+    return shared_type;
+}
+
+/// Empty if the arguments are fine, otherwise what is wrong with them
+pub fn check_builtin_arguments(name: &str, argument_types: &Vec<String>) -> String {
+    let expected_count: usize = get_builtin_argument_count(name);
+    if is_argument_count_valid(name, argument_types.len()) == false {
+        if name == ASSERT {
+            return format!("'{}' takes a condition and an optional message, found {} argument(s)", name, argument_types.len());
+        }
+        return format!("'{}' takes {} argument(s), found {}", name, expected_count, argument_types.len());
+    }
+
+    let signature: &str = get_builtin_signature(name);
+    if signature.is_empty() == false {
+        let parameter_types: Vec<String> = type_utils::get_function_parameter_types(signature);
+        for i in 0..argument_types.len() {
+            let argument_type: &str = &argument_types[i];
+            if argument_type.is_empty() == false && type_utils::types_match(&parameter_types[i], argument_type) == false {
+                return format!("'{}' argument {} must be '{}', found '{}'", name, i + 1, parameter_types[i], argument_type);
+            }
+        }
+        return String::new();
+    }
+
+    if name == LEN {
+        let argument_type: &str = &argument_types[0];
+        let is_sized: bool = argument_type == type_utils::STRING_TYPE || type_utils::is_array_type_text(argument_type);
+        if argument_type.is_empty() == false && is_sized == false {
+            return format!("'len' needs a string or an array, found '{}'", argument_type);
+        }
+        return String::new();
+    }
+
+    if is_channel_function(name) {
+        return check_channel_arguments(name, argument_types);
+    }
+
+    let argument_count: usize = argument_types.len();
+    for i in 0..argument_count {
+        let argument_type: &str = &argument_types[i];
+        if argument_type.is_empty() == false && type_utils::is_numeric_type_text(argument_type) == false {
+            return format!("'{}' needs numbers, argument {} is '{}'", name, i + 1, argument_type);
+        }
+    }
+    for i in 1..argument_count {
+        let first_type: &str = &argument_types[0];
+        let argument_type: &str = &argument_types[i];
+        if first_type.is_empty() || argument_type.is_empty() {
+            continue;
+        }
+        if type_utils::types_match(first_type, argument_type) == false {
+            return format!("'{}' arguments must have the same type, found '{}' and '{}', use a cast", name, first_type, argument_type);
+        }
+    }
+    return String::new();
+}
+
+// send(c, v) needs v to fit the channel, recv(c) only the channel
+fn check_channel_arguments(name: &str, argument_types: &Vec<String>) -> String {
+    let channel_type: &str = &argument_types[0];
+    if channel_type.is_empty() {
+        return String::new();
+    }
+    if type_utils::is_channel_type_text(channel_type) == false {
+        return format!("'{}' needs a channel, found '{}'", name, channel_type);
+    }
+    if name == SEND {
+        let element_type: String = type_utils::get_channel_element_type(channel_type);
+        let value_type: &str = &argument_types[1];
+        if value_type.is_empty() == false && type_utils::types_match(&element_type, value_type) == false {
+            return format!("cannot send '{}' on '{}'", value_type, channel_type);
+        }
+    }
+    return String::new();
+}
+
+/// The type a builtin call gives back, empty when the arguments don't fit
+pub fn get_builtin_call_type(name: &str, argument_types: &Vec<String>) -> String {
+    if check_builtin_arguments(name, argument_types).is_empty() == false {
+        return String::new();
+    }
+    let signature: &str = get_builtin_signature(name);
+    if signature.is_empty() == false {
+        return type_utils::get_function_return_type(signature);
+    }
+    if name == LEN {
+        return "i32".to_string();
+    }
+    if name == SEND {
+        return type_utils::VOID_TYPE.to_string();
+    }
+    if name == RECV {
+        return type_utils::get_channel_element_type(&argument_types[0]);
+    }
+    let shared_type: String = get_shared_argument_type(argument_types);
+    if shared_type.is_empty() {
+        return String::new();
+    }
+    if is_float_math_function(name) {
+        if shared_type == "f32" {
+            return shared_type;
+        }
+        return "f64".to_string();
+    }
+    return get_default_literal_type(&shared_type);
+}
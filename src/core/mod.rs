@@ -1,6 +1,8 @@
-pub mod enums;
-pub mod errors;
-pub mod structs;
-pub mod printing;
-pub mod debugging;
-pub mod token_utils;
\ No newline at end of file
+pub mod enums;
+pub mod errors;
+pub mod structs;
+pub mod printing;
+pub mod debugging;
+pub mod token_utils;
+pub mod type_utils;
+pub mod builtins;
\ No newline at end of file
@@ -4,12 +4,17 @@ pub const RED: &str = "\x1b[31m";
 pub const RESET: &str = "\x1b[0m";
 pub const GREEN: &str = "\x1b[32m";
 pub const YELLOW: &str = "\x1b[33m";
+#[allow(dead_code)]
 pub const MAGENTA: &str = "\x1b[35m";
 pub const CYAN: &str = "\x1b[36m";
+#[allow(dead_code)]
 pub const BLUE: &str = "\x1b[34m";
 pub const ORANGE: &str = "\x1b[38;2;206;145;120m";
 pub const GREY: &str = "\x1b[38;2;156;156;156m";
 pub const CREAM: &str = "\x1b[38;2;220;220;145m";
+#[allow(dead_code)]
 pub const LIGHT_GREEN: &str = "\x1b[38;2;181;206;143m";
+#[allow(dead_code)]
 pub const LIGHT_BLUE: &str = "\x1b[38;2;5;169;173m";
+#[allow(dead_code)]
 pub const PEACH: &str = "\x1b[38;2;255;231;190m";
\ No newline at end of file
@@ -1,259 +1,717 @@
-use crate::core::enums::ASTNodeType;
-use crate::core::enums::TokenType;
-use crate::core::errors::AstError;
-use crate::core::errors::ConvertError;
-
-pub struct ParseData<'lifetimes_suck> {
-    pub token_list: Vec<Token>,
-    pub last_token: i32,
-    pub character_index: usize,
-    pub code: &'lifetimes_suck [u8],
-    pub line_count: usize, //for token position
-    pub char_count: usize,
-    pub was_comment: bool,
-}
-
-pub struct Token {
-    pub text: String,
-    pub token_type: i32,
-    pub line_number: usize,
-    pub char_number: usize,
-}
-
-impl Clone for Token {
-    fn clone(&self) -> Self {
-        Token {
-            text: self.text.clone(),
-            token_type: self.token_type,
-            line_number: self.line_number,
-            char_number: self.char_number,
-        }
-    }
-}
-
-pub fn empty_token() -> Token {
-    return Token {
-        text: String::new(),
-        token_type: TokenType::Na,
-        line_number: 0,
-        char_number: 0,
-    };
-}
-
-pub struct ASTData<'lifetimes_suck> {
-    pub ast_nodes: &'lifetimes_suck mut Vec<ASTNode>,
-    pub token_index: usize,
-    pub token_list: &'lifetimes_suck Vec<Token>,
-    pub error_detail: String,
-    pub error_token: Token,
-    pub error_function: String,
-    pub error_code: i32,
-}
-
-impl<'lifetimes_suck> ASTData<'lifetimes_suck> {
-    pub fn get_token(&mut self) -> Token {
-        if self.token_index >= self.token_list.len() {
-            self.error_code = AstError::IndexOutOfRange;
-            return empty_token();
-        }
-        return self.token_list[self.token_index].clone();
-    }
-
-    pub fn token_index_in_bounds(&self) -> bool {
-        if self.token_index >= self.token_list.len() {
-            return false;
-        }
-        return true;
-    }
-
-    pub fn is_error(&self) -> bool {
-        return self.error_code != 0;
-    }
-    pub fn append_node(&mut self, node: ASTNode) {
-        self.ast_nodes.push(node);
-    }
-    pub fn increment_index(&mut self) -> bool {
-        if self.token_index + 1 >= self.token_list.len() {
-            self.error_code = AstError::UnexpectedEndOfFile;
-            return false;
-        }
-        self.token_index += 1;
-        return true;
-    }
-    pub fn expect_type(&mut self, expected_type: i32, error_message: &str) -> bool {
-        let token: Token = self.get_token();
-        if self.is_error() {
-            return false;
-        }
-        if token.token_type != expected_type {
-            self.error_detail = error_message.to_string();
-            self.error_code = AstError::MissingExpectedType;
-            return false;
-        }
-        return true;
-    }
-}
-
-pub fn create_raw_pointer<T>(variable: T) -> *mut T {
-    return Box::into_raw(Box::new(variable));
-}
-
-pub struct ASTNode {
-    pub node_type: i32,
-    pub token: *mut Token,  // Just store it directly, use a default/dummy value
-    pub left: *mut ASTNode, // null pointer = no left node
-    pub middle: *mut ASTNode,
-    pub right: *mut ASTNode,
-    pub children: Vec<ASTNode>,
-    pub is_const: bool,
-    pub size: usize,
-}
-
-impl Clone for ASTNode {
-    fn clone(&self) -> Self {
-        ASTNode {
-            node_type: self.node_type,
-            token: self.token.clone(),
-            left: self.left.clone(),
-            middle: self.middle.clone(),
-            right: self.right.clone(),
-            children: self.children.clone(),
-            is_const: self.is_const,
-            size: self.size,
-        }
-    }
-}
-
-pub fn get_default_node() -> ASTNode {
-    return ASTNode {
-        node_type: ASTNodeType::Invalid,
-        token: std::ptr::null_mut(),
-        left: std::ptr::null_mut(),
-        middle: std::ptr::null_mut(),
-        right: std::ptr::null_mut(),
-        children: Vec::new(),
-        is_const: false,
-        size: 0,
-    };
-}
-
-pub struct StringBuilder {
-    buffer: Vec<u8>,
-}
-
-impl StringBuilder {
-    /// Create a new StringBuilder
-    pub fn new() -> Self {
-        Self { buffer: Vec::new() }
-    }
-
-    /// Create a new StringBuilder with capacity
-    pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            buffer: Vec::with_capacity(capacity),
-        }
-    }
-
-    /// Append a string
-    pub fn append(&mut self, s: &str) {
-        self.buffer.extend_from_slice(s.as_bytes());
-    }
-
-    /// Append with formatting
-    pub fn append_fmt(&mut self, s: String) {
-        self.buffer.extend_from_slice(s.as_bytes());
-    }
-
-    /// Append a line (adds newline)
-    pub fn append_line(&mut self, s: &str) {
-        self.buffer.extend_from_slice(s.as_bytes());
-        self.buffer.push(b'\n');
-    }
-
-    /// Append formatted line
-    pub fn append_line_fmt(&mut self, s: String) {
-        self.buffer.extend_from_slice(s.as_bytes());
-        self.buffer.push(b'\n');
-    }
-
-    /// Get the string as a slice
-    pub fn as_str(&self) -> &str {
-        unsafe { std::str::from_utf8_unchecked(&self.buffer) }
-    }
-
-    /// Convert to owned String (consumes self)
-    pub fn into_string(self) -> String {
-        unsafe {
-            return String::from_utf8_unchecked(self.buffer);
-        }
-    }
-
-    /// Clear the buffer
-    pub fn clear(&mut self) {
-        self.buffer.clear();
-    }
-
-    /// Get length
-    pub fn len(&self) -> usize {
-        self.buffer.len()
-    }
-
-    /// Check if empty
-    pub fn is_empty(&self) -> bool {
-        self.buffer.is_empty()
-    }
-}
-impl Clone for StringBuilder {
-    fn clone(&self) -> Self {
-        StringBuilder {
-            buffer: self.buffer.clone(),
-        }
-    }
-}
-
-pub struct ConvertData<'lifetimes_suck> {
-    pub ast_nodes:&'lifetimes_suck Vec<ASTNode>,
-    pub node_index:usize,
-    pub error_code:i32,
-    pub error_detail:String,
-    pub error_token:Token,
-    pub error_function:String,
-    pub code_builder:&'lifetimes_suck mut StringBuilder,
-    pub temp_var_count:usize,
-    pub function_return_type:String,
-    pub index_count:usize,
-}
-
-impl<'lifetimes_suck> ConvertData<'lifetimes_suck> {
-
-    pub fn is_error(&self) -> bool {
-        return self.error_code != ConvertError::None;
-    }
-
-    pub fn get_node(&mut self) -> ASTNode {
-        if self.node_index >= self.ast_nodes.len() {
-            self.error_code = ConvertError::InvalidNodeType;
-            return get_default_node();
-        }
-        return self.ast_nodes[self.node_index].clone();
-    }
-
-    pub fn increment_index_count(&mut self) {
-        self.index_count += 1;
-    }
-
-    pub fn decrement_index_count(&mut self) {
-        if self.index_count == 0 {
-            return;
-        }
-        self.index_count -= 1;
-    }
-
-    pub fn print_type(&self, node_ptr:*mut ASTNode) -> Token {
-    unsafe {
-        let node:ASTNode = (*node_ptr).clone();
-        let token_ptr:*mut Token = node.token;
-        return (*token_ptr).clone(); 
-    }
-    }
-}
+use crate::core::enums;
+use crate::core::enums::ASTNodeType;
+use crate::core::enums::SymbolKind;
+use crate::core::enums::TokenType;
+use crate::core::enums::UninitializedMode;
+use crate::core::enums::UnusedVariableMode;
+use crate::core::errors::AstError;
+use crate::core::errors::ConvertError;
+use crate::core::errors::ModuleError;
+use crate::core::errors::SemanticError;
+
+pub struct ParseData<'lifetimes_suck> {
+    pub token_list: Vec<Token>,
+    pub last_token: i32,
+    pub character_index: usize,
+    pub code: &'lifetimes_suck [u8],
+    pub line_count: usize, //for token position
+    pub char_count: usize,
+    pub line_start_index: usize, //character_index of the first character on the current line
+    pub was_comment: bool,
+    pub file_index: usize,
+}
+
+pub struct Token {
+    pub text: String,
+    pub token_type: i32,
+    pub line_number: usize,
+    pub char_number: usize,
+    pub file_index: usize, // index into the program's source files
+}
+
+impl Clone for Token {
+    fn clone(&self) -> Self {
+        Token {
+            text: self.text.clone(),
+            token_type: self.token_type,
+            line_number: self.line_number,
+            char_number: self.char_number,
+            file_index: self.file_index,
+        }
+    }
+}
+
+pub fn empty_token() -> Token {
+    return Token {
+        text: String::new(),
+        token_type: TokenType::Na,
+        line_number: 0,
+        char_number: 0,
+        file_index: 0,
+    };
+}
+
+/// One file of the program, tokens point back to it with file_index
+pub struct SourceFile {
+    pub path: String,
+    pub code: String,
+}
+
+/// What the command line asked for, e.g. compiler main.src --unused=error
+pub struct CompileOptions {
+    pub unused_variable_mode: i32,
+    pub uninitialized_mode: i32,
+    pub lint_rules: Vec<i32>,        // LintRule values that are reported, none unless asked for
+    pub max_nesting_depth: usize,    // blocks inside a function body, the body itself doesn't count
+    pub max_function_lines: usize,   // from the name's line to the closing brace's
+}
+
+impl CompileOptions {
+
+    pub fn new() -> Self {
+        Self {
+            unused_variable_mode: UnusedVariableMode::Blank,
+            uninitialized_mode: UninitializedMode::Error,
+            lint_rules: Vec::new(),
+            max_nesting_depth: 4,
+            max_function_lines: 60,
+        }
+    }
+
+    pub fn is_lint_enabled(&self, lint_rule:i32) -> bool {
+        let rule_count:usize = self.lint_rules.len();
+        for i in 0..rule_count {
+            if self.lint_rules[i] == lint_rule {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    pub fn enable_lint(&mut self, lint_rule:i32) {
+        if self.is_lint_enabled(lint_rule) == false {
+            self.lint_rules.push(lint_rule);
+        }
+    }
+
+    pub fn disable_lint(&mut self, lint_rule:i32) {
+        let mut i:usize = 0;
+        while i < self.lint_rules.len() {
+            if self.lint_rules[i] == lint_rule {
+                self.lint_rules.remove(i);
+                continue;
+            }
+            i += 1;
+        }
+    }
+}
+
+pub struct ModuleData {
+    pub source_files: Vec<SourceFile>,
+    pub canonical_paths: Vec<String>, // same order as source_files, "" for code not read from disk
+    pub import_stack: Vec<usize>,     // files still loading their imports, a repeat means a cycle
+    pub ast_nodes: Vec<ASTNode>,      // every file's top level nodes, imports first
+    pub tokens: Vec<Token>,
+    pub error_code: i32,
+    pub error_detail: String,
+    pub error_token: Token,
+}
+
+impl ModuleData {
+
+    pub fn new() -> Self {
+        Self {
+            source_files: Vec::new(),
+            canonical_paths: Vec::new(),
+            import_stack: Vec::new(),
+            ast_nodes: Vec::new(),
+            tokens: Vec::new(),
+            error_code: ModuleError::None,
+            error_detail: String::new(),
+            error_token: empty_token(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        return self.error_code != ModuleError::None;
+    }
+}
+
+pub struct ASTData<'lifetimes_suck> {
+    pub ast_nodes: &'lifetimes_suck mut Vec<ASTNode>,
+    pub token_index: usize,
+    pub token_list: &'lifetimes_suck Vec<Token>,
+    pub error_detail: String,
+    pub error_token: Token,
+    pub error_function: String,
+    pub error_code: i32,
+}
+
+impl<'lifetimes_suck> ASTData<'lifetimes_suck> {
+    pub fn get_token(&mut self) -> Token {
+        if self.token_index >= self.token_list.len() {
+            self.error_code = AstError::IndexOutOfRange;
+            return empty_token();
+        }
+        return self.token_list[self.token_index].clone();
+    }
+
+    pub fn token_index_in_bounds(&self) -> bool {
+        if self.token_index >= self.token_list.len() {
+            return false;
+        }
+        return true;
+    }
+
+    pub fn is_error(&self) -> bool {
+        return self.error_code != 0;
+    }
+    pub fn append_node(&mut self, node: ASTNode) {
+        self.ast_nodes.push(node);
+    }
+    pub fn increment_index(&mut self) -> bool {
+        if self.token_index + 1 >= self.token_list.len() {
+            self.error_code = AstError::UnexpectedEndOfFile;
+            return false;
+        }
+        self.token_index += 1;
+        return true;
+    }
+    pub fn expect_type(&mut self, expected_type: i32, error_message: &str) -> bool {
+        let token: Token = self.get_token();
+        if self.is_error() {
+            return false;
+        }
+        if token.token_type != expected_type {
+            self.error_detail = error_message.to_string();
+            self.error_code = AstError::MissingExpectedType;
+            return false;
+        }
+        return true;
+    }
+}
+
+pub fn create_raw_pointer<T>(variable: T) -> *mut T {
+    return Box::into_raw(Box::new(variable));
+}
+
+pub struct ASTNode {
+    pub node_type: i32,
+    pub token: *mut Token,  // Just store it directly, use a default/dummy value
+    pub left: *mut ASTNode, // null pointer = no left node
+    pub middle: *mut ASTNode,
+    pub right: *mut ASTNode,
+    pub children: Vec<ASTNode>,
+    pub is_const: bool,
+    pub size: usize,
+}
+
+impl Clone for ASTNode {
+    fn clone(&self) -> Self {
+        ASTNode {
+            node_type: self.node_type,
+            token: self.token.clone(),
+            left: self.left.clone(),
+            middle: self.middle.clone(),
+            right: self.right.clone(),
+            children: self.children.clone(),
+            is_const: self.is_const,
+            size: self.size,
+        }
+    }
+}
+
+pub fn get_default_node() -> ASTNode {
+    return ASTNode {
+        node_type: ASTNodeType::Invalid,
+        token: std::ptr::null_mut(),
+        left: std::ptr::null_mut(),
+        middle: std::ptr::null_mut(),
+        right: std::ptr::null_mut(),
+        children: Vec::new(),
+        is_const: false,
+        size: 0,
+    };
+}
+
+pub struct StringBuilder {
+    buffer: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl StringBuilder {
+    /// Create a new StringBuilder
+    pub fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    /// Create a new StringBuilder with capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Append a string
+    pub fn append(&mut self, s: &str) {
+        self.buffer.extend_from_slice(s.as_bytes());
+    }
+
+    /// Append with formatting
+    pub fn append_fmt(&mut self, s: String) {
+        self.buffer.extend_from_slice(s.as_bytes());
+    }
+
+    /// Append a line (adds newline)
+    pub fn append_line(&mut self, s: &str) {
+        self.buffer.extend_from_slice(s.as_bytes());
+        self.buffer.push(b'\n');
+    }
+
+    /// Append formatted line
+    pub fn append_line_fmt(&mut self, s: String) {
+        self.buffer.extend_from_slice(s.as_bytes());
+        self.buffer.push(b'\n');
+    }
+
+    /// Get the string as a slice
+    pub fn as_str(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(&self.buffer) }
+    }
+
+    /// Convert to owned String (consumes self)
+    pub fn into_string(self) -> String {
+        unsafe {
+            return String::from_utf8_unchecked(self.buffer);
+        }
+    }
+
+    /// Clear the buffer
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Get length
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+}
+impl Clone for StringBuilder {
+    fn clone(&self) -> Self {
+        StringBuilder {
+            buffer: self.buffer.clone(),
+        }
+    }
+}
+
+pub struct Symbol {
+    pub name: String,
+    pub type_text: String, // source type, e.g. "i32", "*u8"
+    pub symbol_kind: i32,
+    pub scope_depth: usize,
+    pub token: Token,
+    pub is_const: bool,
+    pub type_parameters: Vec<String>, // generic functions only, e.g. ["T"]
+    pub is_used: bool,                // read somewhere, assigning with = alone doesn't count
+}
+
+impl Clone for Symbol {
+    fn clone(&self) -> Self {
+        Symbol {
+            name: self.name.clone(),
+            type_text: self.type_text.clone(),
+            symbol_kind: self.symbol_kind,
+            scope_depth: self.scope_depth,
+            token: self.token.clone(),
+            is_const: self.is_const,
+            type_parameters: self.type_parameters.clone(),
+            is_used: self.is_used,
+        }
+    }
+}
+
+pub fn empty_symbol() -> Symbol {
+    return Symbol {
+        name: String::new(),
+        type_text: String::new(),
+        symbol_kind: SymbolKind::None,
+        scope_depth: 0,
+        token: empty_token(),
+        is_const: false,
+        type_parameters: Vec::new(),
+        is_used: false,
+    };
+}
+
+pub struct SymbolTable {
+    pub symbols: Vec<Symbol>,
+    pub scope_depth: usize,
+}
+
+impl SymbolTable {
+
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope_depth: 0,
+        }
+    }
+
+    pub fn enter_scope(&mut self) {
+        self.scope_depth += 1;
+    }
+
+    /// Drops every symbol declared in the scope being left
+    pub fn exit_scope(&mut self) {
+        if self.scope_depth == 0 {
+            return;
+        }
+        while self.symbols.len() > 0 {
+            let last_index: usize = self.symbols.len() - 1;
+            if self.symbols[last_index].scope_depth < self.scope_depth {
+                break;
+            }
+            self.symbols.pop();
+        }
+        self.scope_depth -= 1;
+    }
+
+    pub fn add_symbol(&mut self, name: &str, type_text: &str, symbol_kind: i32, token: Token, is_const: bool) {
+        self.symbols.push(Symbol {
+            name: name.to_string(),
+            type_text: type_text.to_string(),
+            symbol_kind,
+            scope_depth: self.scope_depth,
+            token,
+            is_const,
+            type_parameters: Vec::new(),
+            is_used: false,
+        });
+    }
+
+    pub fn add_function_symbol(&mut self, name: &str, type_text: &str, token: Token, type_parameters: Vec<String>) {
+        self.symbols.push(Symbol {
+            name: name.to_string(),
+            type_text: type_text.to_string(),
+            symbol_kind: SymbolKind::Function,
+            scope_depth: self.scope_depth,
+            token,
+            is_const: true,
+            type_parameters,
+            is_used: false,
+        });
+    }
+
+    /// Only looks in the innermost scope, empty_symbol() if the name isn't declared there
+    pub fn get_scope_symbol(&self, name: &str) -> Symbol {
+        let mut i: usize = self.symbols.len();
+        while i > 0 {
+            i -= 1;
+            if self.symbols[i].scope_depth < self.scope_depth {
+                break;
+            }
+            if self.symbols[i].name == name {
+                return self.symbols[i].clone();
+            }
+        }
+        return empty_symbol();
+    }
+
+    /// Marks the symbol the name resolves to as read
+    pub fn mark_used(&mut self, name: &str) {
+        let mut i: usize = self.symbols.len();
+        while i > 0 {
+            i -= 1;
+            if self.symbols[i].name == name {
+                self.symbols[i].is_used = true;
+                return;
+            }
+        }
+    }
+
+    /// Searches from the innermost scope outwards, empty_symbol() if not found
+    pub fn get_symbol(&self, name: &str) -> Symbol {
+        let mut i: usize = self.symbols.len();
+        while i > 0 {
+            i -= 1;
+            if self.symbols[i].name == name {
+                return self.symbols[i].clone();
+            }
+        }
+        return empty_symbol();
+    }
+}
+
+/// A Go variable standing in for an expression Go can't write inline, e.g. a ternary
+pub struct TempVar {
+    pub node: *const ASTNode,
+    pub name: String,
+}
+
+/// A generic function's type parameters and how much each has to support, see semantic_generics
+pub struct GenericConstraints {
+    pub function_name: String,
+    pub type_parameters: Vec<String>,
+    pub constraints: Vec<usize>, // one per type parameter
+}
+
+/// A loop being written, for break and continue inside it to find
+pub struct LoopLabel {
+    pub name: String,           // empty when the loop has no label
+    pub continue_label: String, // Go label continue jumps to, empty to use Go's continue
+}
+
+/// A local followed by the semantic pass's definite assignment check
+pub struct InitVariable {
+    pub name: String,
+    pub token: Token,        // where it is declared
+    pub is_assigned: bool,   // every path to here has given it a value
+}
+
+impl Clone for InitVariable {
+    fn clone(&self) -> Self {
+        InitVariable {
+            name: self.name.clone(),
+            token: self.token.clone(),
+            is_assigned: self.is_assigned,
+        }
+    }
+}
+
+/// A loop being checked, with what was assigned at each break and continue inside it
+pub struct InitLoop {
+    pub label: String,                  // empty when the loop has no label
+    pub break_states: Vec<Vec<bool>>,    // one is_assigned per InitVariable, as at the break
+    pub continue_states: Vec<Vec<bool>>,
+}
+
+/// "src/main.src:12:5", where a token sits in the program's source
+pub fn get_source_location(source_paths:&Vec<String>, token:&Token) -> String {
+    let mut path:String = String::new();
+    if token.file_index < source_paths.len() {
+        path = source_paths[token.file_index].clone();
+    }
+    //lines count from 1 like the error messages do
+    return format!("{}:{}:{}", path, token.line_number + 1, token.char_number);
+}
+
+/// Something that compiles but is probably a mistake, reported without stopping
+pub struct Warning {
+    pub detail: String,
+    pub token: Token,
+}
+
+/// What the semantic pass works out that convert needs too
+pub struct SemanticResult {
+    pub unused_variables:Vec<Token>,
+    pub generic_constraints:Vec<GenericConstraints>,
+}
+
+pub struct SemanticData<'lifetimes_suck> {
+    pub ast_nodes:&'lifetimes_suck Vec<ASTNode>,
+    pub error_code:i32,
+    pub error_detail:String,
+    pub error_token:Token,
+    pub error_function:String,
+    pub symbol_table:SymbolTable,
+    pub source_paths:Vec<String>,
+    pub function_return_type:String,
+    pub warnings:Vec<Warning>,
+    pub unused_variable_mode:i32,
+    pub unused_variables:Vec<Token>, // declarations convert follows with _ = x
+    pub uninitialized_mode:i32,
+    pub init_variables:Vec<InitVariable>,
+    pub init_loops:Vec<InitLoop>,
+    pub generic_constraints:Vec<GenericConstraints>, // convert writes them as the type parameters' constraints
+}
+
+impl<'lifetimes_suck> SemanticData<'lifetimes_suck> {
+
+    pub fn is_error(&self) -> bool {
+        return self.error_code != SemanticError::None;
+    }
+
+    /// The FunctionDeclaration node named name, null if the program declares none
+    pub fn get_function_declaration(&self, name:&str) -> *const ASTNode {
+        let node_count:usize = self.ast_nodes.len();
+        for i in 0..node_count {
+            let node:&ASTNode = &self.ast_nodes[i];
+            if node.node_type != ASTNodeType::FunctionDeclaration || node.token.is_null() {
+                continue;
+            }
+            unsafe {
+                if (*node.token).text == name {
+                    return node as *const ASTNode;
+                }
+            }
+        }
+        return std::ptr::null();
+    }
+
+    pub fn add_warning(&mut self, token:&Token, detail:String) {
+        self.warnings.push(Warning {
+            detail,
+            token: token.clone(),
+        });
+    }
+
+    pub fn get_source_location(&self, token:&Token) -> String {
+        return get_source_location(&self.source_paths, token);
+    }
+}
+
+pub struct LintData<'lifetimes_suck> {
+    pub tokens:&'lifetimes_suck Vec<Token>,
+    pub options:&'lifetimes_suck CompileOptions,
+    pub symbol_table:SymbolTable, // only what shadowing needs, semantic has already checked the names
+    pub source_paths:Vec<String>,
+    pub warnings:Vec<Warning>,
+}
+
+impl<'lifetimes_suck> LintData<'lifetimes_suck> {
+
+    /// Rules that are turned off are dropped here, so a check doesn't have to ask first
+    pub fn add_warning(&mut self, lint_rule:i32, token:&Token, detail:String) {
+        if self.options.is_lint_enabled(lint_rule) == false {
+            return;
+        }
+        self.warnings.push(Warning {
+            detail: format!("{} ({})", detail, enums::lint_rule_to_string(lint_rule)),
+            token: token.clone(),
+        });
+    }
+
+    pub fn get_source_location(&self, token:&Token) -> String {
+        return get_source_location(&self.source_paths, token);
+    }
+}
+
+pub struct ConvertData<'lifetimes_suck> {
+    pub ast_nodes:&'lifetimes_suck Vec<ASTNode>,
+    pub node_index:usize,
+    pub error_code:i32,
+    pub error_detail:String,
+    pub error_token:Token,
+    pub error_function:String,
+    pub code_builder:&'lifetimes_suck mut StringBuilder,
+    pub temp_var_count:usize,
+    pub function_return_type:String,
+    pub index_count:usize,
+    pub symbol_table:SymbolTable,
+    pub temp_vars:Vec<TempVar>,
+    pub helpers:Vec<String>,
+    pub go_imports:Vec<String>,
+    pub source_paths:Vec<String>,
+    pub loop_labels:Vec<LoopLabel>,
+    pub function_labels:Vec<String>,
+    pub unused_variables:&'lifetimes_suck Vec<Token>,
+    pub generic_constraints:&'lifetimes_suck Vec<GenericConstraints>,
+}
+
+impl<'lifetimes_suck> ConvertData<'lifetimes_suck> {
+
+    pub fn is_error(&self) -> bool {
+        return self.error_code != ConvertError::None;
+    }
+
+    pub fn get_node(&mut self) -> ASTNode {
+        if self.node_index >= self.ast_nodes.len() {
+            self.error_code = ConvertError::InvalidNodeType;
+            return get_default_node();
+        }
+        return self.ast_nodes[self.node_index].clone();
+    }
+
+    pub fn increment_index_count(&mut self) {
+        self.index_count += 1;
+    }
+
+    pub fn decrement_index_count(&mut self) {
+        if self.index_count == 0 {
+            return;
+        }
+        self.index_count -= 1;
+    }
+
+    pub fn create_temp_var(&mut self, node:*const ASTNode, prefix:&str) -> String {
+        let name:String = format!("__{}_{}", prefix, self.temp_var_count);
+        self.temp_var_count += 1;
+        self.temp_vars.push(TempVar {
+            node,
+            name: name.clone(),
+        });
+        return name;
+    }
+
+    /// True if the semantic pass found the declaration's name is never read
+    pub fn is_unused_variable(&self, name_token:&Token) -> bool {
+        let unused_count:usize = self.unused_variables.len();
+        for i in 0..unused_count {
+            let unused:&Token = &self.unused_variables[i];
+            if unused.file_index == name_token.file_index
+                && unused.line_number == name_token.line_number
+                && unused.char_number == name_token.char_number {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    /// Empty if the node was never given a temp var
+    pub fn get_temp_var(&self, node:*const ASTNode) -> String {
+        let temp_var_count:usize = self.temp_vars.len();
+        for i in 0..temp_var_count {
+            if self.temp_vars[i].node == node {
+                return self.temp_vars[i].name.clone();
+            }
+        }
+        return String::new();
+    }
+
+    /// Helpers are written once at the end of the file, however many times they are used
+    pub fn add_helper(&mut self, name:&str) {
+        let helper_count:usize = self.helpers.len();
+        for i in 0..helper_count {
+            if self.helpers[i] == name {
+                return;
+            }
+        }
+        self.helpers.push(name.to_string());
+    }
+
+    /// Records a Go package the generated code uses, the import block is written from these
+    pub fn add_import(&mut self, package:&str) {
+        let import_count:usize = self.go_imports.len();
+        for i in 0..import_count {
+            if self.go_imports[i] == package {
+                return;
+            }
+        }
+        self.go_imports.push(package.to_string());
+    }
+
+    /// "src/main.src:12:5", where a token sits in the program's source
+    pub fn get_source_location(&self, token:&Token) -> String {
+        return get_source_location(&self.source_paths, token);
+    }
+
+    pub fn append_indentation(&mut self) {
+        for _ in 0..self.index_count {
+            self.code_builder.append("\t");
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn print_type(&self, node_ptr:*mut ASTNode) -> Token {
+    unsafe {
+        let node:ASTNode = (*node_ptr).clone();
+        let token_ptr:*mut Token = node.token;
+        return (*token_ptr).clone(); 
+    }
+    }
+}
@@ -1,74 +1,208 @@
-use crate::core::enums;
-use crate::core::structs;
-use enums::TokenType;
-use structs::Token;
-
-pub fn is_type_token(token: Token) -> bool {
-    if is_var_type(token.token_type) == true {
-        return true;
-    }
-    if token.token_type == TokenType::Identifier {
-        return true;
-    }
-    return false;
-}
-
-pub fn is_binary_operator_bool(tokenType: i32) -> bool {
-    return tokenType == TokenType::Plus
-        || tokenType == TokenType::Minus
-        || tokenType == TokenType::Multiply
-        || tokenType == TokenType::Divide
-        || tokenType == TokenType::AndAnd
-        || tokenType == TokenType::OrOr
-        || tokenType == TokenType::LessThan
-        || tokenType == TokenType::LessThanEquals
-        || tokenType == TokenType::GreaterThan
-        || tokenType == TokenType::GreaterThanEquals
-        || tokenType == TokenType::EqualsEquals
-        || tokenType == TokenType::NotEquals;
-}
-
-pub fn is_var_type(token_type: i32) -> bool {
-    return token_type == TokenType::Bool
-        || token_type == TokenType::Char
-        || token_type == TokenType::Int
-        || token_type == TokenType::f32
-        || token_type == TokenType::f64
-        || token_type == TokenType::i16
-        || token_type == TokenType::i32
-        || token_type == TokenType::i64
-        || token_type == TokenType::i8
-        || token_type == TokenType::u16
-        || token_type == TokenType::u32
-        || token_type == TokenType::u64
-        || token_type == TokenType::Usize
-        || token_type == TokenType::u8
-        || token_type == TokenType::String
-        || token_type == TokenType::Void;
-}
-
-pub fn is_integer_var_type(token_type: i32) -> bool {
-    return token_type == TokenType::Int
-        || token_type == TokenType::i16
-        || token_type == TokenType::i32
-        || token_type == TokenType::i64
-        || token_type == TokenType::i8
-        || token_type == TokenType::u16
-        || token_type == TokenType::u32
-        || token_type == TokenType::u64
-        || token_type == TokenType::Usize
-        || token_type == TokenType::u8;
-}
-
-pub fn get_precedence_bool(token_type:i32) -> usize {
-    
-    match token_type {
-        TokenType::OrOr => return 1, // lowest
-        TokenType::AndAnd => return 2,
-        TokenType::EqualsEquals | TokenType::NotEquals => return 3,
-        TokenType::LessThan | TokenType::GreaterThan | TokenType::LessThanEquals | TokenType::GreaterThanEquals => return 4,
-        TokenType::Plus | TokenType::Minus => return 5,
-        TokenType::Multiply | TokenType::Divide => return 6,
-        _ => return 0,
-    }
-}
\ No newline at end of file
+use crate::core::enums;
+use crate::core::structs;
+use enums::TokenType;
+use structs::Token;
+
+pub fn is_type_token(token: Token) -> bool {
+    if is_var_type(token.token_type) == true {
+        return true;
+    }
+    if token.token_type == TokenType::Identifier {
+        return true;
+    }
+    return false;
+}
+
+pub fn is_binary_operator_bool(token_type: i32) -> bool {
+    return token_type == TokenType::Plus
+        || token_type == TokenType::Minus
+        || token_type == TokenType::Multiply
+        || token_type == TokenType::Divide
+        || token_type == TokenType::Modulus
+        || token_type == TokenType::AndAnd
+        || token_type == TokenType::OrOr
+        || token_type == TokenType::LessThan
+        || token_type == TokenType::LessThanEquals
+        || token_type == TokenType::GreaterThan
+        || token_type == TokenType::GreaterThanEquals
+        || token_type == TokenType::EqualsEquals
+        || token_type == TokenType::NotEquals;
+}
+
+pub fn is_var_type(token_type: i32) -> bool {
+    return token_type == TokenType::Bool
+        || token_type == TokenType::Char
+        || token_type == TokenType::Int
+        || token_type == TokenType::f32
+        || token_type == TokenType::f64
+        || token_type == TokenType::i16
+        || token_type == TokenType::i32
+        || token_type == TokenType::i64
+        || token_type == TokenType::i8
+        || token_type == TokenType::u16
+        || token_type == TokenType::u32
+        || token_type == TokenType::u64
+        || token_type == TokenType::Usize
+        || token_type == TokenType::u8
+        || token_type == TokenType::String
+        || token_type == TokenType::Void;
+}
+
+pub fn is_integer_var_type(token_type: i32) -> bool {
+    return token_type == TokenType::Int
+        || token_type == TokenType::i16
+        || token_type == TokenType::i32
+        || token_type == TokenType::i64
+        || token_type == TokenType::i8
+        || token_type == TokenType::u16
+        || token_type == TokenType::u32
+        || token_type == TokenType::u64
+        || token_type == TokenType::Usize
+        || token_type == TokenType::u8;
+}
+
+/// The smallest and largest value an integer type holds, int is i32 and usize is 64 bits like Go's uint
+pub fn get_integer_range(token_type: i32) -> (i128, i128) {
+    match token_type {
+        TokenType::i8 => return (i8::MIN as i128, i8::MAX as i128),
+        TokenType::u8 => return (0, u8::MAX as i128),
+        TokenType::i16 => return (i16::MIN as i128, i16::MAX as i128),
+        TokenType::u16 => return (0, u16::MAX as i128),
+        TokenType::Int | TokenType::i32 => return (i32::MIN as i128, i32::MAX as i128),
+        TokenType::u32 => return (0, u32::MAX as i128),
+        TokenType::i64 => return (i64::MIN as i128, i64::MAX as i128),
+        TokenType::u64 | TokenType::Usize => return (0, u64::MAX as i128),
+        _ => return (0, 0),
+    }
+}
+
+pub fn get_precedence_bool(token_type:i32) -> usize {
+    
+    match token_type {
+        TokenType::OrOr => return 1, // lowest
+        TokenType::AndAnd => return 2,
+        TokenType::EqualsEquals | TokenType::NotEquals => return 3,
+        TokenType::LessThan | TokenType::GreaterThan | TokenType::LessThanEquals | TokenType::GreaterThanEquals => return 4,
+        TokenType::Plus | TokenType::Minus => return 5,
+        TokenType::Multiply | TokenType::Divide | TokenType::Modulus => return 6,
+        _ => return 0,
+    }
+}
+
+// The binary operator a compound assignment uses, += is +
+pub fn get_compound_operator(token_type:i32) -> i32 {
+    match token_type {
+        TokenType::PlusEquals | TokenType::PlusPlus => return TokenType::Plus,
+        TokenType::MinusEquals | TokenType::MinusMinus => return TokenType::Minus,
+        TokenType::MultiplyEquals => return TokenType::Multiply,
+        TokenType::DivideEquals => return TokenType::Divide,
+        TokenType::ModulusEquals => return TokenType::Modulus,
+        _ => return TokenType::Equals,
+    }
+}
+
+/// How many single character inserts, deletes, swaps of one character or swaps of two
+/// neighbouring characters turn first into second, "whlie" is one away from "while"
+pub fn get_edit_distance(first: &str, second: &str) -> usize {
+    let first_characters: Vec<char> = first.chars().collect();
+    let second_characters: Vec<char> = second.chars().collect();
+    let first_count: usize = first_characters.len();
+    let second_count: usize = second_characters.len();
+
+    //distances[i][j] is the distance between first's first i characters and second's first j
+    let mut distances: Vec<Vec<usize>> = vec![vec![0; second_count + 1]; first_count + 1];
+    for i in 0..first_count + 1 {
+        distances[i][0] = i;
+    }
+    for j in 0..second_count + 1 {
+        distances[0][j] = j;
+    }
+    for i in 1..first_count + 1 {
+        for j in 1..second_count + 1 {
+            let mut substitution_cost: usize = 1;
+            if first_characters[i - 1] == second_characters[j - 1] {
+                substitution_cost = 0;
+            }
+            let mut distance: usize = std::cmp::min(distances[i - 1][j] + 1, distances[i][j - 1] + 1);
+            distance = std::cmp::min(distance, distances[i - 1][j - 1] + substitution_cost);
+
+            let is_swap: bool = i > 1 && j > 1
+                && first_characters[i - 1] == second_characters[j - 2]
+                && first_characters[i - 2] == second_characters[j - 1];
+            if is_swap {
+                distance = std::cmp::min(distance, distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    return distances[first_count][second_count];
+}
+
+/// ", did you mean 'println'?" for the closest candidate, empty when none is close enough to be the intent
+pub fn get_suggestion_text(name: &str, candidates: &Vec<String>) -> String {
+    //a third of the word can be wrong, short words get one mistake
+    let max_distance: usize = std::cmp::max(1, name.chars().count() / 3);
+    let mut closest: String = String::new();
+    let mut closest_distance: usize = max_distance + 1;
+
+    let candidate_count: usize = candidates.len();
+    for i in 0..candidate_count {
+        if candidates[i] == name {
+            continue;
+        }
+        let distance: usize = get_edit_distance(name, &candidates[i]);
+        //changing every character of a short name, e.g. 'b' into 'a', is a different name rather than a typo
+        if distance >= name.chars().count() || distance >= candidates[i].chars().count() {
+            continue;
+        }
+        //on a tie a name that was cut short wins, i3 is more likely i32 than i8
+        let is_better_tie: bool = distance == closest_distance
+            && candidates[i].starts_with(name)
+            && closest.starts_with(name) == false;
+        if distance < closest_distance || is_better_tie {
+            closest = candidates[i].clone();
+            closest_distance = distance;
+        }
+    }
+    if closest.is_empty() {
+        return String::new();
+    }
+    return format!(", did you mean '{}'?", closest);
+}
+
+// Names the generated Go gives a meaning of its own, a program using one would come out as Go that doesn't build
+const GO_KEYWORDS: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough", "for", "func", "go", "goto",
+    "if", "import", "interface", "map", "package", "range", "return", "select", "struct", "switch", "type", "var",
+];
+// The packages convert can import, a name declared as one hides the package
+const GO_PACKAGES: &[&str] = &["bufio", "fmt", "math", "os", "strconv", "strings", "sync"];
+// Go's own names that the generated code writes, e.g. int32(len(s)) or make(chan int32)
+const GO_PREDECLARED: &[&str] = &[
+    "any", "append", "bool", "byte", "false", "float32", "float64", "int", "int8", "int16", "int32", "int64",
+    "len", "make", "nil", "panic", "rune", "string", "true", "uint", "uint8", "uint16", "uint32", "uint64", "uintptr",
+];
+// Temp vars, labels, helpers, constraints and the wrapped main all start with it
+pub const GENERATED_NAME_PREFIX: &str = "__";
+
+/// Why name can't be used in the generated Go, empty when it can
+pub fn get_go_name_clash(name: &str) -> String {
+    if GO_KEYWORDS.contains(&name) {
+        return format!("'{}' is a Go keyword, the generated code can't use it as a name", name);
+    }
+    if GO_PACKAGES.contains(&name) {
+        return format!("'{}' is a Go package the generated code can import, the name would hide it", name);
+    }
+    if GO_PREDECLARED.contains(&name) {
+        return format!("'{}' is a name Go predeclares and the generated code uses, the name would hide it", name);
+    }
+    if name.starts_with(GENERATED_NAME_PREFIX) {
+        return format!("'{}' starts with '{}', those names are kept for the code the compiler writes", name, GENERATED_NAME_PREFIX);
+    }
+    return String::new();
+}
+
+/// A struct field only has to stay clear of the keywords, Go looks fields up through their struct
+pub fn is_go_keyword(name: &str) -> bool {
+    return GO_KEYWORDS.contains(&name);
+}
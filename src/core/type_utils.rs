@@ -0,0 +1,628 @@
+use crate::core::builtins;
+use crate::core::enums;
+use crate::core::structs;
+use crate::core::token_utils;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use enums::TokenType;
+use structs::ASTNode;
+use structs::GenericConstraints;
+use structs::Symbol;
+use structs::SymbolTable;
+use structs::Token;
+
+// Literals have no type of their own until they meet a typed value
+pub const INTEGER_LITERAL_TYPE: &str = "{integer}";
+pub const FLOAT_LITERAL_TYPE: &str = "{float}";
+pub const BOOL_TYPE: &str = "bool";
+pub const STRING_TYPE: &str = "string";
+pub const CHAR_TYPE: &str = "char";
+pub const VOID_TYPE: &str = "void";
+pub const WAIT_GROUP_TYPE: &str = "wait_group";
+pub const MUTEX_TYPE: &str = "mutex";
+
+pub fn type_token_to_string(token: &Token) -> String {
+    match token.token_type {
+        TokenType::i8 => return "i8".to_string(),
+        TokenType::u8 => return "u8".to_string(),
+        TokenType::i16 => return "i16".to_string(),
+        TokenType::u16 => return "u16".to_string(),
+        TokenType::Int | TokenType::i32 => return "i32".to_string(),
+        TokenType::u32 => return "u32".to_string(),
+        TokenType::i64 => return "i64".to_string(),
+        TokenType::u64 => return "u64".to_string(),
+        TokenType::Usize => return "usize".to_string(),
+        TokenType::f32 => return "f32".to_string(),
+        TokenType::f64 => return "f64".to_string(),
+        TokenType::String => return STRING_TYPE.to_string(),
+        TokenType::Bool => return BOOL_TYPE.to_string(),
+        TokenType::Char => return CHAR_TYPE.to_string(),
+        TokenType::Void => return VOID_TYPE.to_string(),
+        _ => return token.text.clone(),
+    }
+}
+
+/// Flattens a type node chain (Pointer -> VarType) into its source text, e.g. "*i32"
+pub fn type_node_to_string(node_ptr: *mut ASTNode) -> String {
+    unsafe {
+        if node_ptr.is_null() {
+            return String::new();
+        }
+        return type_node_ref_to_string(&*node_ptr);
+    }
+}
+
+pub fn type_node_ref_to_string(node: &ASTNode) -> String {
+    unsafe {
+        if node.node_type == ASTNodeType::Pointer {
+            let inner_text: String = type_node_to_string(node.left);
+            return format!("*{}", inner_text);
+        }
+        if node.node_type == ASTNodeType::Array {
+            let element_text: String = type_node_to_string(node.left);
+            return format!("{}[]", element_text);
+        }
+        if node.node_type == ASTNodeType::FunctionType {
+            return function_type_to_string(node.middle, node.right);
+        }
+        if node.node_type == ASTNodeType::Channel {
+            let element_text: String = type_node_to_string(node.left);
+            return format!("chan<{}>", element_text);
+        }
+        if node.token.is_null() {
+            return String::new();
+        }
+        return type_token_to_string(&*node.token);
+    }
+}
+
+/// "fn(i32, i32) -> i32" from a list of parameter type nodes and a return type node.
+/// parameters_ptr children can be type nodes or Parameter nodes holding the type on the left.
+pub fn function_type_to_string(parameters_ptr: *mut ASTNode, return_type_ptr: *mut ASTNode) -> String {
+    unsafe {
+        let mut type_text: String = "fn(".to_string();
+
+        if parameters_ptr.is_null() == false {
+            let parameters: &ASTNode = &*parameters_ptr;
+            let parameter_count: usize = parameters.children.len();
+
+            for i in 0..parameter_count {
+                let parameter: &ASTNode = &parameters.children[i];
+                if i != 0 {
+                    type_text.push_str(", ");
+                }
+                if parameter.node_type == ASTNodeType::Parameter {
+                    type_text.push_str(&type_node_to_string(parameter.left));
+                } else {
+                    type_text.push_str(&type_node_ref_to_string(parameter));
+                }
+            }
+        }
+        type_text.push(')');
+
+        let return_text: String = type_node_to_string(return_type_ptr);
+        if return_text.is_empty() == false && return_text != VOID_TYPE {
+            type_text.push_str(" -> ");
+            type_text.push_str(&return_text);
+        }
+        return type_text;
+    }
+}
+
+/// The type a FunctionDeclaration would have as a value
+pub fn function_declaration_type_text(node: &ASTNode) -> String {
+    return function_type_to_string(node.middle, node.left);
+}
+
+pub fn is_function_type_text(type_text: &str) -> bool {
+    return type_text.starts_with("fn(");
+}
+
+// Index of the ')' closing the '(' at open_index
+fn find_closing_parenthesis(type_text: &str, open_index: usize) -> usize {
+    let bytes: &[u8] = type_text.as_bytes();
+    let mut depth: usize = 0;
+    let mut i: usize = open_index;
+
+    while i < bytes.len() {
+        if bytes[i] == b'(' {
+            depth += 1;
+        }
+        if bytes[i] == b')' {
+            depth -= 1;
+            if depth == 0 {
+                return i;
+            }
+        }
+        i += 1;
+    }
+    return bytes.len();
+}
+
+/// "fn(i32, i32) -> i32" gives "i32", a function type without '->' gives "void"
+pub fn get_function_return_type(type_text: &str) -> String {
+    if is_function_type_text(type_text) == false {
+        return String::new();
+    }
+    let close_index: usize = find_closing_parenthesis(type_text, 2);
+    let rest: &str = &type_text[close_index + 1..];
+    if rest.starts_with(" -> ") {
+        return rest[4..].to_string();
+    }
+    return VOID_TYPE.to_string();
+}
+
+/// "fn(i32, fn(u8) -> u8)" gives ["i32", "fn(u8) -> u8"]
+pub fn get_function_parameter_types(type_text: &str) -> Vec<String> {
+    if is_function_type_text(type_text) == false {
+        return Vec::new();
+    }
+    return split_type_list(type_text, 2);
+}
+
+/// Several values at once, only builtins give these, e.g. "(i32, bool)"
+pub fn is_tuple_type_text(type_text: &str) -> bool {
+    return type_text.starts_with('(');
+}
+
+pub fn get_tuple_types(type_text: &str) -> Vec<String> {
+    if is_tuple_type_text(type_text) == false {
+        return Vec::new();
+    }
+    return split_type_list(type_text, 0);
+}
+
+// The comma separated types inside the brackets opening at open_index
+fn split_type_list(type_text: &str, open_index: usize) -> Vec<String> {
+    let mut parameter_types: Vec<String> = Vec::new();
+    let close_index: usize = find_closing_parenthesis(type_text, open_index);
+    let bytes: &[u8] = type_text.as_bytes();
+
+    let mut depth: usize = 0;
+    let mut current: String = String::new();
+    let mut i: usize = open_index + 1;
+
+    while i < close_index {
+        let character: char = bytes[i] as char;
+        if character == '(' {
+            depth += 1;
+        }
+        if character == ')' {
+            depth -= 1;
+        }
+        if character == ',' && depth == 0 {
+            parameter_types.push(current.trim().to_string());
+            current.clear();
+            i += 1;
+            continue;
+        }
+        current.push(character);
+        i += 1;
+    }
+    if current.trim().is_empty() == false {
+        parameter_types.push(current.trim().to_string());
+    }
+    return parameter_types;
+}
+
+pub fn is_array_type_text(type_text: &str) -> bool {
+    return type_text.ends_with("[]") && is_function_type_text(type_text) == false;
+}
+
+/// "string[]" gives "string"
+pub fn get_array_element_type(type_text: &str) -> String {
+    if is_array_type_text(type_text) == false {
+        return String::new();
+    }
+    return type_text[..type_text.len() - 2].to_string();
+}
+
+pub fn is_channel_type_text(type_text: &str) -> bool {
+    return type_text.starts_with("chan<") && type_text.ends_with('>');
+}
+
+/// "chan<i32>" gives "i32"
+pub fn get_channel_element_type(type_text: &str) -> String {
+    if is_channel_type_text(type_text) == false {
+        return String::new();
+    }
+    return type_text[5..type_text.len() - 1].to_string();
+}
+
+/// wait_group and mutex are shared by reference, copies all lock the same one
+pub fn is_sync_type_text(type_text: &str) -> bool {
+    return type_text == WAIT_GROUP_TYPE || type_text == MUTEX_TYPE;
+}
+
+/// The type s[i] gives, a char for strings, empty if the value can't be indexed
+pub fn get_index_result_type(type_text: &str) -> String {
+    if type_text == STRING_TYPE {
+        return CHAR_TYPE.to_string();
+    }
+    return get_array_element_type(type_text);
+}
+
+/// The struct name behind a value's type, pointers to structs are followed like Go does
+pub fn get_struct_name(type_text: &str) -> String {
+    let mut struct_name: &str = type_text;
+    while struct_name.starts_with('*') {
+        struct_name = &struct_name[1..];
+    }
+    return struct_name.to_string();
+}
+
+pub fn get_field_symbol_name(struct_name: &str, field_name: &str) -> String {
+    return format!("{}.{}", struct_name, field_name);
+}
+
+/// The type token a type's text came from, TokenType::Na for types without one, e.g. structs
+pub fn get_type_text_token_type(type_text: &str) -> i32 {
+    match type_text {
+        "i8" => return TokenType::i8,
+        "u8" => return TokenType::u8,
+        "i16" => return TokenType::i16,
+        "u16" => return TokenType::u16,
+        "i32" => return TokenType::i32,
+        "u32" => return TokenType::u32,
+        "i64" => return TokenType::i64,
+        "u64" => return TokenType::u64,
+        "usize" => return TokenType::Usize,
+        "f32" => return TokenType::f32,
+        "f64" => return TokenType::f64,
+        STRING_TYPE => return TokenType::String,
+        BOOL_TYPE => return TokenType::Bool,
+        CHAR_TYPE => return TokenType::Char,
+        VOID_TYPE => return TokenType::Void,
+        _ => return TokenType::Na,
+    }
+}
+
+pub fn is_integer_type_text(type_text: &str) -> bool {
+    return type_text == "i8"
+        || type_text == "u8"
+        || type_text == "i16"
+        || type_text == "u16"
+        || type_text == "i32"
+        || type_text == "u32"
+        || type_text == "i64"
+        || type_text == "u64"
+        || type_text == "usize"
+        || type_text == INTEGER_LITERAL_TYPE;
+}
+
+pub fn is_float_type_text(type_text: &str) -> bool {
+    return type_text == "f32" || type_text == "f64" || type_text == FLOAT_LITERAL_TYPE;
+}
+
+pub fn is_numeric_type_text(type_text: &str) -> bool {
+    return is_integer_type_text(type_text) || is_float_type_text(type_text);
+}
+
+pub fn is_literal_type_text(type_text: &str) -> bool {
+    return type_text == INTEGER_LITERAL_TYPE || type_text == FLOAT_LITERAL_TYPE;
+}
+
+/// Exact match, except untyped number literals may take the type they meet
+pub fn types_match(expected: &str, actual: &str) -> bool {
+    if expected == actual {
+        return true;
+    }
+    if actual == INTEGER_LITERAL_TYPE {
+        return is_numeric_type_text(expected);
+    }
+    if actual == FLOAT_LITERAL_TYPE {
+        return is_float_type_text(expected);
+    }
+    if expected == INTEGER_LITERAL_TYPE || expected == FLOAT_LITERAL_TYPE {
+        return types_match(actual, expected);
+    }
+    return false;
+}
+
+pub fn is_type_parameter(type_text: &str, symbol_table: &SymbolTable) -> bool {
+    let symbol: Symbol = symbol_table.get_symbol(type_text);
+    return symbol.symbol_kind == SymbolKind::TypeParameter;
+}
+
+/// types_match for the two sides of an operator, an integer literal can also meet a type parameter, e.g. x * 2
+pub fn operand_types_match(left_type: &str, right_type: &str, symbol_table: &SymbolTable) -> bool {
+    if types_match(left_type, right_type) {
+        return true;
+    }
+    if left_type == INTEGER_LITERAL_TYPE {
+        return is_type_parameter(right_type, symbol_table);
+    }
+    if right_type == INTEGER_LITERAL_TYPE {
+        return is_type_parameter(left_type, symbol_table);
+    }
+    return false;
+}
+
+/// Only number and char types can be converted with a cast
+pub fn is_castable_type_text(type_text: &str) -> bool {
+    return is_numeric_type_text(type_text) || type_text == CHAR_TYPE;
+}
+
+pub fn is_comparison_operator(token_type: i32) -> bool {
+    return token_type == TokenType::LessThan
+        || token_type == TokenType::LessThanEquals
+        || token_type == TokenType::GreaterThan
+        || token_type == TokenType::GreaterThanEquals
+        || token_type == TokenType::EqualsEquals
+        || token_type == TokenType::NotEquals;
+}
+
+pub fn is_logical_operator(token_type: i32) -> bool {
+    return token_type == TokenType::AndAnd || token_type == TokenType::OrOr;
+}
+
+/// Strings only join and compare, bools only combine and compare for equality.
+/// Other types are left to Go.
+pub fn is_operator_valid_for_type(token_type: i32, type_text: &str) -> bool {
+    if type_text == STRING_TYPE {
+        return token_type == TokenType::Plus || is_comparison_operator(token_type);
+    }
+    if type_text == BOOL_TYPE {
+        return is_logical_operator(token_type) || token_type == TokenType::EqualsEquals || token_type == TokenType::NotEquals;
+    }
+    if is_numeric_type_text(type_text) || type_text == CHAR_TYPE {
+        return is_logical_operator(token_type) == false;
+    }
+    return true;
+}
+
+pub fn is_binary_node(node: &ASTNode) -> bool {
+    if node.token.is_null() || node.left.is_null() || node.right.is_null() {
+        return false;
+    }
+    unsafe {
+        let token_type: i32 = (*node.token).token_type;
+        return token_utils::is_binary_operator_bool(token_type);
+    }
+}
+
+/// A bare true, e.g. the condition of while (true)
+pub fn is_true_literal(node: &ASTNode) -> bool {
+    if node.node_type != ASTNodeType::BoolLiteral || node.token.is_null() {
+        return false;
+    }
+    unsafe {
+        return (*node.token).text == "true";
+    }
+}
+
+/// The type of both sides of a binary expression, a literal side takes the other side's type
+pub fn get_operand_type(left_type: &str, right_type: &str) -> String {
+    if is_literal_type_text(left_type) == false {
+        return left_type.to_string();
+    }
+    if is_literal_type_text(right_type) == false {
+        return right_type.to_string();
+    }
+    if left_type == FLOAT_LITERAL_TYPE || right_type == FLOAT_LITERAL_TYPE {
+        return FLOAT_LITERAL_TYPE.to_string();
+    }
+    return INTEGER_LITERAL_TYPE.to_string();
+}
+
+fn is_type_name_character(character: char) -> bool {
+    return character.is_ascii_alphanumeric() || character == '_';
+}
+
+/// True if name appears as a whole type name anywhere in type_text, e.g. "mutex" in "fn(mutex)"
+pub fn type_text_uses_name(type_text: &str, name: &str) -> bool {
+    let mut current: String = String::new();
+    let characters: Vec<char> = type_text.chars().collect();
+    let character_count: usize = characters.len();
+
+    for i in 0..character_count + 1 {
+        if i < character_count && is_type_name_character(characters[i]) {
+            current.push(characters[i]);
+            continue;
+        }
+        if current == name {
+            return true;
+        }
+        current.clear();
+    }
+    return false;
+}
+
+/// ["T", "U"] for fn <T, U>, empty for a normal function
+pub fn get_type_parameter_names(node: &ASTNode) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let child_count: usize = node.children.len();
+    for i in 0..child_count {
+        let child: &ASTNode = &node.children[i];
+        if child.node_type != ASTNodeType::TypeParameter || child.token.is_null() {
+            continue;
+        }
+        unsafe {
+            names.push((*child.token).text.clone());
+        }
+    }
+    return names;
+}
+
+/// Swaps whole type names only, so "T" in "fn(T) -> Tree" leaves "Tree" alone
+pub fn substitute_type_parameters(type_text: &str, type_parameters: &Vec<String>, type_arguments: &Vec<String>) -> String {
+    let mut result: String = String::new();
+    let mut current: String = String::new();
+    let characters: Vec<char> = type_text.chars().collect();
+    let character_count: usize = characters.len();
+
+    for i in 0..character_count + 1 {
+        if i < character_count && is_type_name_character(characters[i]) {
+            current.push(characters[i]);
+            continue;
+        }
+        if current.is_empty() == false {
+            let mut replacement: String = current.clone();
+            let parameter_count: usize = type_parameters.len();
+            for parameter_index in 0..parameter_count {
+                if type_parameters[parameter_index] == current && parameter_index < type_arguments.len() {
+                    replacement = type_arguments[parameter_index].clone();
+                    break;
+                }
+            }
+            result.push_str(&replacement);
+            current.clear();
+        }
+        if i < character_count {
+            result.push(characters[i]);
+        }
+    }
+    return result;
+}
+
+/// The source text of each type in a generic call's TypeArguments node
+pub fn get_type_argument_texts(node: &ASTNode) -> Vec<String> {
+    let mut type_arguments: Vec<String> = Vec::new();
+    if node.middle.is_null() {
+        return type_arguments;
+    }
+    unsafe {
+        let type_arguments_node: &ASTNode = &*node.middle;
+        let argument_count: usize = type_arguments_node.children.len();
+        for i in 0..argument_count {
+            type_arguments.push(type_node_ref_to_string(&type_arguments_node.children[i]));
+        }
+    }
+    return type_arguments;
+}
+
+// How much a type parameter has to support, the highest one its function needs wins
+pub const CONSTRAINT_ANY: usize = 0;
+pub const CONSTRAINT_COMPARABLE: usize = 1;
+pub const CONSTRAINT_ORDERED: usize = 2;
+pub const CONSTRAINT_NUMBER: usize = 3;
+pub const CONSTRAINT_INTEGER: usize = 4;
+
+/// Whether a type argument can stand in for a type parameter limited to constraint, a char is a byte in Go
+pub fn is_constraint_satisfied(type_text: &str, constraint: usize) -> bool {
+    match constraint {
+        CONSTRAINT_COMPARABLE => return is_array_type_text(type_text) == false && is_function_type_text(type_text) == false,
+        CONSTRAINT_ORDERED => return is_numeric_type_text(type_text) || type_text == CHAR_TYPE || type_text == STRING_TYPE,
+        CONSTRAINT_NUMBER => return is_numeric_type_text(type_text) || type_text == CHAR_TYPE,
+        CONSTRAINT_INTEGER => return is_integer_type_text(type_text) || type_text == CHAR_TYPE,
+        _ => return true,
+    }
+}
+
+/// How a constraint reads in an error, e.g. "a number type"
+pub fn get_constraint_text(constraint: usize) -> &'static str {
+    match constraint {
+        CONSTRAINT_COMPARABLE => return "a type that can be compared with ==",
+        CONSTRAINT_ORDERED => return "a number, char or string type",
+        CONSTRAINT_NUMBER => return "a number type",
+        CONSTRAINT_INTEGER => return "an integer type",
+        _ => return "any type",
+    }
+}
+
+/// Where function_name's constraints are, the count when it isn't generic
+pub fn get_generic_constraints_index(generic_constraints: &Vec<GenericConstraints>, function_name: &str) -> usize {
+    let generic_count: usize = generic_constraints.len();
+    for i in 0..generic_count {
+        if generic_constraints[i].function_name == function_name {
+            return i;
+        }
+    }
+    return generic_count;
+}
+
+/// The callee's type at a call site, with a generic function's type arguments filled in
+pub fn get_call_function_type(node: &ASTNode, symbol_table: &SymbolTable) -> String {
+    unsafe {
+        if node.left.is_null() {
+            return String::new();
+        }
+        let function_node: &ASTNode = &*node.left;
+        let function_type: String = get_expression_type(function_node, symbol_table);
+        if function_node.node_type != ASTNodeType::Identifier {
+            return function_type;
+        }
+        let symbol: Symbol = symbol_table.get_symbol(&(*function_node.token).text);
+        let type_arguments: Vec<String> = get_type_argument_texts(node);
+        //unknown until the call is given the right type arguments, the call reports that
+        if symbol.type_parameters.len() != type_arguments.len() {
+            return String::new();
+        }
+        if type_arguments.len() == 0 {
+            return function_type;
+        }
+        return substitute_type_parameters(&function_type, &symbol.type_parameters, &type_arguments);
+    }
+}
+
+/// Returns the source type of an expression, empty if it can't be worked out
+pub fn get_expression_type(node: &ASTNode, symbol_table: &SymbolTable) -> String {
+    unsafe {
+        match node.node_type {
+            ASTNodeType::IntegerLiteral => return INTEGER_LITERAL_TYPE.to_string(),
+            ASTNodeType::FloatLiteral => return FLOAT_LITERAL_TYPE.to_string(),
+            ASTNodeType::StringLiteral => return STRING_TYPE.to_string(),
+            ASTNodeType::CharLiteral => return CHAR_TYPE.to_string(),
+            ASTNodeType::BoolLiteral => return BOOL_TYPE.to_string(),
+            ASTNodeType::Identifier => {
+                let name: String = (*node.token).text.clone();
+                if builtins::is_builtin_constant_node(node, symbol_table) {
+                    return builtins::get_builtin_constant_type(&name);
+                }
+                let symbol: Symbol = symbol_table.get_symbol(&name);
+                return symbol.type_text;
+            }
+            ASTNodeType::Cast => return type_node_to_string(node.left),
+            ASTNodeType::FunctionCall => {
+                if builtins::is_builtin_call(node, symbol_table) {
+                    let argument_types: Vec<String> = builtins::get_argument_types(node, symbol_table);
+                    return builtins::get_builtin_call_type(&(*node.token).text, &argument_types);
+                }
+                let function_type: String = get_call_function_type(node, symbol_table);
+                return get_function_return_type(&function_type);
+            }
+            ASTNodeType::FieldAccess => {
+                let struct_type: String = get_expression_type(&*node.left, symbol_table);
+                let struct_name: String = get_struct_name(&struct_type);
+                let field_name: String = get_field_symbol_name(&struct_name, &(*node.token).text);
+                let field: Symbol = symbol_table.get_symbol(&field_name);
+                return field.type_text;
+            }
+            ASTNodeType::ArrayAccess => {
+                let value_type: String = get_expression_type(&*node.left, symbol_table);
+                return get_index_result_type(&value_type);
+            }
+            ASTNodeType::Ternary => {
+                let true_type: String = get_expression_type(&*node.middle, symbol_table);
+                let false_type: String = get_expression_type(&*node.right, symbol_table);
+                if types_match(&true_type, &false_type) == false {
+                    return String::new();
+                }
+                return get_operand_type(&true_type, &false_type);
+            }
+            ASTNodeType::Minus => {
+                if node.left.is_null() {
+                    return String::new();
+                }
+                return get_expression_type(&*node.left, symbol_table);
+            }
+            _ => {}
+        }
+
+        if is_binary_node(node) == false {
+            return String::new();
+        }
+
+        let operator_type: i32 = (*node.token).token_type;
+        if is_comparison_operator(operator_type) || is_logical_operator(operator_type) {
+            return BOOL_TYPE.to_string();
+        }
+
+        let left_type: String = get_expression_type(&*node.left, symbol_table);
+        let right_type: String = get_expression_type(&*node.right, symbol_table);
+        if operand_types_match(&left_type, &right_type, symbol_table) == false {
+            return String::new();
+        }
+        return get_operand_type(&left_type, &right_type);
+    }
+}
@@ -1,73 +1,119 @@
-use crate::core::debugging;
-use crate::core::enums;
-use crate::core::errors;
-use crate::core::printing;
-use crate::core::structs;
-use crate::format::ast_functions;
-
-use enums::ASTNodeType;
-use enums::TokenType;
-use errors::AstError;
-use structs::ASTData;
-use structs::ASTNode;
-use structs::Token;
-
-pub fn build_asts(token_list: &Vec<Token>, code: &String, format_error: &mut i32) -> Vec<ASTNode> {
-    
-    print!("\t{}Formatting{}\t\t\t", printing::GREY, printing::RESET);
-
-    let mut ast_nodes: Vec<ASTNode> = Vec::new();
-
-    let mut ast_data = ASTData {
-        ast_nodes: &mut ast_nodes,
-        token_list: token_list,
-        token_index: 0,
-        error_code: AstError::None,
-        error_function: String::new(),
-        error_detail: String::new(),
-        error_token: structs::empty_token(),
-    };
-
-    let token_count: usize = ast_data.token_list.len();
-
-    while ast_data.token_index < token_count {
-        
-        let index_before: usize = ast_data.token_index;
-
-        process_global_token_ast(&mut ast_data, false);
-        if ast_data.is_error() {
-            println!("{}Error{}\n", printing::RED, printing::RESET);
-            debugging::print_ast_error(&ast_data, code);
-            *format_error = ast_data.error_code;
-            return ast_data.ast_nodes.clone();
-        }
-
-        if index_before == ast_data.token_index {
-            ast_data.token_index += 1;
-        }
-    }
-
-    print!("{}Done{}\n", printing::CYAN, printing::RESET);
-
-    return ast_data.ast_nodes.clone();
-}
-
-fn process_global_token_ast(ast_data: &mut ASTData, is_const: bool) {
-    ast_data.error_function = "processGlobalTokenAST".to_string();
-
-    let first_token: Token = ast_data.get_token();
-
-    match first_token.token_type {
-
-        TokenType::Fn => {
-            ast_functions::process_function_declaration(ast_data);
-        }
-        _ => {
-            ast_data.error_detail  = "unimplemented type in ast ".to_string();
-            ast_data.error_token = first_token;
-            ast_data.error_code = AstError::UnimplementedType;
-            return;
-        }
-    }
-}
-
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::printing;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::format::ast_declarations;
+use crate::format::ast_functions;
+use crate::format::ast_imports;
+use crate::format::ast_structs;
+use crate::parse::parse_utils;
+
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::SourceFile;
+use structs::Token;
+
+pub fn build_asts(token_list: &Vec<Token>, source_file: &SourceFile, format_error: &mut i32) -> Vec<ASTNode> {
+    
+    print!("\t{}Formatting{}\t\t\t", printing::GREY, printing::RESET);
+
+    let mut ast_nodes: Vec<ASTNode> = Vec::new();
+
+    let mut ast_data = ASTData {
+        ast_nodes: &mut ast_nodes,
+        token_list,
+        token_index: 0,
+        error_code: AstError::None,
+        error_function: String::new(),
+        error_detail: String::new(),
+        error_token: structs::empty_token(),
+    };
+
+    let token_count: usize = ast_data.token_list.len();
+
+    while ast_data.token_index < token_count {
+        
+        let index_before: usize = ast_data.token_index;
+
+        process_global_token_ast(&mut ast_data);
+        if ast_data.is_error() {
+            println!("{}Error{}\n", printing::RED, printing::RESET);
+            debugging::print_ast_error(&ast_data, source_file);
+            *format_error = ast_data.error_code;
+            return ast_data.ast_nodes.clone();
+        }
+
+        if index_before == ast_data.token_index {
+            ast_data.token_index += 1;
+        }
+    }
+
+    print!("{}Done{}\n", printing::CYAN, printing::RESET);
+
+    return ast_data.ast_nodes.clone();
+}
+
+fn process_global_token_ast(ast_data: &mut ASTData) {
+    ast_data.error_function = "processGlobalTokenAST".to_string();
+
+    let first_token: Token = ast_data.get_token();
+
+    match first_token.token_type {
+
+        //fn(i32) -> i32 op = add; is a variable, a function's name never follows a '('
+        TokenType::Fn if get_next_token_type(ast_data) == TokenType::LeftParenthesis => {
+            ast_declarations::process_global_declaration(ast_data);
+        }
+        TokenType::Fn => {
+            ast_functions::process_function_declaration(ast_data);
+        }
+        TokenType::Struct => {
+            ast_structs::process_struct_declaration(ast_data);
+        }
+        TokenType::Import => {
+            ast_imports::process_import(ast_data);
+        }
+        TokenType::Const | TokenType::Chan => {
+            ast_declarations::process_global_declaration(ast_data);
+        }
+        _ if is_global_declaration_start(ast_data, &first_token) => {
+            ast_declarations::process_global_declaration(ast_data);
+        }
+        _ => {
+            ast_data.error_detail  = "unimplemented type in ast ".to_string();
+            let suggestion: String = parse_utils::get_keyword_suggestion(&first_token.text);
+            if suggestion.is_empty() == false {
+                ast_data.error_detail = format!("unexpected '{}'{}", first_token.text, suggestion);
+            }
+            ast_data.error_token = first_token;
+            ast_data.error_code = AstError::UnimplementedType;
+            return;
+        }
+    }
+}
+
+
+// i32 count = 0; or Point origin; a struct name is only a type when a name or [] follows it,
+// otherwise it's a misspelled keyword and gets a suggestion
+fn is_global_declaration_start(ast_data: &ASTData, first_token: &Token) -> bool {
+    if token_utils::is_var_type(first_token.token_type) {
+        return first_token.token_type != TokenType::Void;
+    }
+    if first_token.token_type != TokenType::Identifier {
+        return false;
+    }
+    let next_token_type: i32 = get_next_token_type(ast_data);
+    return next_token_type == TokenType::Identifier || next_token_type == TokenType::LeftSquareBracket;
+}
+
+fn get_next_token_type(ast_data: &ASTData) -> i32 {
+    let next_index: usize = ast_data.token_index + 1;
+    if next_index >= ast_data.token_list.len() {
+        return TokenType::Na;
+    }
+    return ast_data.token_list[next_index].token_type;
+}
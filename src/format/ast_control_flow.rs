@@ -0,0 +1,389 @@
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::format::ast_declarations;
+use crate::format::ast_expressions;
+use crate::format::ast_functions;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::Token;
+
+// (condition), the brackets are required like C
+fn parse_condition(ast_data: &mut ASTData, keyword_token: &Token) -> ASTNode {
+    let mut condition_node: ASTNode = structs::get_default_node();
+
+    if ast_data.expect_type(TokenType::LeftParenthesis, &format!("Missing expected '(' after {}", keyword_token.text)) == false {
+        ast_data.error_token = keyword_token.clone();
+        return condition_node;
+    }
+    if ast_data.increment_index() == false {
+        return condition_node;
+    }
+
+    condition_node = ast_expressions::parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+    if ast_data.is_error() {
+        return condition_node;
+    }
+    if condition_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = format!("Missing condition after {}", keyword_token.text);
+        ast_data.error_token = keyword_token.clone();
+        ast_data.error_code = AstError::MissingExpectedType;
+        return condition_node;
+    }
+
+    if ast_data.expect_type(TokenType::RightParenthesis, &format!("Missing expected ')' after {} condition", keyword_token.text)) == false {
+        ast_data.error_token = keyword_token.clone();
+        return condition_node;
+    }
+    ast_data.token_index += 1;
+
+    return condition_node;
+}
+
+// { statements }
+fn parse_block(ast_data: &mut ASTData, keyword_token: &Token, node_type: i32) -> ASTNode {
+    if ast_data.expect_type(TokenType::LeftBrace, &format!("Missing expected '{{' after {}", keyword_token.text)) == false {
+        ast_data.error_token = keyword_token.clone();
+        return structs::get_default_node();
+    }
+    ast_data.token_index += 1;
+
+    let block_node: ASTNode = ast_functions::build_body_block(ast_data, node_type);
+    if ast_data.is_error() {
+        return block_node;
+    }
+
+    if ast_data.expect_type(TokenType::RightBrace, &format!("Missing expected '}}' to close {}", keyword_token.text)) == false {
+        ast_data.error_token = keyword_token.clone();
+        return block_node;
+    }
+    ast_data.token_index += 1;
+
+    return block_node;
+}
+
+pub fn process_if(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processIf".to_string();
+
+    let mut if_node: ASTNode = structs::get_default_node();
+
+    let if_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return if_node;
+    }
+
+    let condition_node: ASTNode = parse_condition(ast_data, &if_token);
+    if ast_data.is_error() {
+        return if_node;
+    }
+    let body_node: ASTNode = parse_block(ast_data, &if_token, ASTNodeType::IfBody);
+    if ast_data.is_error() {
+        return if_node;
+    }
+
+    //Node_type: IfStatement
+    //Token:     if
+    //Left:      condition
+    //Middle:    IfBody
+    //Right:     Else, null without one
+    if_node.node_type = ASTNodeType::IfStatement;
+    if_node.token = structs::create_raw_pointer(if_token);
+    if_node.left = structs::create_raw_pointer(condition_node);
+    if_node.middle = structs::create_raw_pointer(body_node);
+
+    if ast_data.token_index_in_bounds() == false {
+        return if_node;
+    }
+    let else_token: Token = ast_data.get_token();
+    if else_token.token_type != TokenType::Else {
+        return if_node;
+    }
+    if ast_data.increment_index() == false {
+        return if_node;
+    }
+
+    //Node_type: Else
+    //Token:     else
+    //Left:      IfStatement for else if, otherwise ElseBody
+    let mut else_node: ASTNode = structs::get_default_node();
+    else_node.node_type = ASTNodeType::Else;
+    else_node.token = structs::create_raw_pointer(else_token.clone());
+
+    let token: Token = ast_data.get_token();
+    if token.token_type == TokenType::If {
+        let else_if_node: ASTNode = process_if(ast_data);
+        if ast_data.is_error() {
+            return if_node;
+        }
+        else_node.left = structs::create_raw_pointer(else_if_node);
+    } else {
+        let else_body_node: ASTNode = parse_block(ast_data, &else_token, ASTNodeType::ElseBody);
+        if ast_data.is_error() {
+            return if_node;
+        }
+        else_node.left = structs::create_raw_pointer(else_body_node);
+    }
+    if_node.right = structs::create_raw_pointer(else_node);
+
+    return if_node;
+}
+
+pub fn process_while(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processWhile".to_string();
+
+    let mut while_node: ASTNode = structs::get_default_node();
+
+    let while_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return while_node;
+    }
+
+    let condition_node: ASTNode = parse_condition(ast_data, &while_token);
+    if ast_data.is_error() {
+        return while_node;
+    }
+    let body_node: ASTNode = parse_block(ast_data, &while_token, ASTNodeType::WhileBody);
+    if ast_data.is_error() {
+        return while_node;
+    }
+
+    //Node_type: WhileLoop
+    //Token:     while
+    //Left:      condition
+    //Right:     WhileBody
+    while_node.node_type = ASTNodeType::WhileLoop;
+    while_node.token = structs::create_raw_pointer(while_token);
+    while_node.left = structs::create_raw_pointer(condition_node);
+    while_node.right = structs::create_raw_pointer(body_node);
+
+    return while_node;
+}
+
+// do { } while (condition); runs the body before checking
+pub fn process_do_while(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processDoWhile".to_string();
+
+    let mut do_while_node: ASTNode = structs::get_default_node();
+
+    let do_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return do_while_node;
+    }
+
+    let body_node: ASTNode = parse_block(ast_data, &do_token, ASTNodeType::WhileBody);
+    if ast_data.is_error() {
+        return do_while_node;
+    }
+
+    let while_token: Token = ast_data.get_token();
+    if ast_data.expect_type(TokenType::While, "Missing expected 'while' after do body") == false {
+        ast_data.error_token = do_token;
+        return do_while_node;
+    }
+    if ast_data.increment_index() == false {
+        return do_while_node;
+    }
+    let condition_node: ASTNode = parse_condition(ast_data, &while_token);
+    if ast_data.is_error() {
+        return do_while_node;
+    }
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after do while condition") == false {
+        ast_data.error_token = while_token;
+        return do_while_node;
+    }
+    ast_data.token_index += 1;
+
+    //Node_type: DoWhileLoop
+    //Token:     do
+    //Left:      condition, checked after each pass
+    //Right:     WhileBody
+    do_while_node.node_type = ASTNodeType::DoWhileLoop;
+    do_while_node.token = structs::create_raw_pointer(do_token);
+    do_while_node.left = structs::create_raw_pointer(condition_node);
+    do_while_node.right = structs::create_raw_pointer(body_node);
+
+    return do_while_node;
+}
+
+// for (i32 i = 0; i < count; i++), each part can be left empty
+pub fn process_for(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processFor".to_string();
+
+    let mut for_node: ASTNode = structs::get_default_node();
+
+    let for_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return for_node;
+    }
+    if ast_data.expect_type(TokenType::LeftParenthesis, "Missing expected '(' after for") == false {
+        ast_data.error_token = for_token;
+        return for_node;
+    }
+    if ast_data.increment_index() == false {
+        return for_node;
+    }
+
+    //Node_type: ForCondition
+    //Token:     for
+    //Left:      Declaration or Assignment run once, null if empty
+    //Middle:    condition, null if empty
+    //Right:     Assignment run after each pass, null if empty
+    let mut for_condition_node: ASTNode = structs::get_default_node();
+    for_condition_node.node_type = ASTNodeType::ForCondition;
+    for_condition_node.token = structs::create_raw_pointer(for_token.clone());
+
+    //both statements eat their own ';'
+    let init_token: Token = ast_data.get_token();
+    if init_token.token_type == TokenType::Semicolon {
+        ast_data.token_index += 1;
+    } else {
+        let init_node: ASTNode = if init_token.token_type == TokenType::Identifier {
+            ast_declarations::process_identifier_statement(ast_data)
+        } else {
+            ast_declarations::process_declaration(ast_data)
+        };
+        if ast_data.is_error() {
+            return for_node;
+        }
+        let is_valid_init: bool = init_node.node_type == ASTNodeType::Declaration || init_node.node_type == ASTNodeType::Assignment;
+        if is_valid_init == false {
+            ast_data.error_detail = "a for loop starts with a declaration or an assignment".to_string();
+            ast_data.error_token = init_token;
+            ast_data.error_code = AstError::UnexpectedType;
+            return for_node;
+        }
+        for_condition_node.left = structs::create_raw_pointer(init_node);
+    }
+    ast_data.error_function = "processFor".to_string();
+
+    let condition_token: Token = ast_data.get_token();
+    if condition_token.token_type != TokenType::Semicolon {
+        let condition_node: ASTNode = ast_expressions::parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+        if ast_data.is_error() {
+            return for_node;
+        }
+        for_condition_node.middle = structs::create_raw_pointer(condition_node);
+    }
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after for condition") == false {
+        ast_data.error_token = for_token;
+        return for_node;
+    }
+    if ast_data.increment_index() == false {
+        return for_node;
+    }
+
+    let step_token: Token = ast_data.get_token();
+    if step_token.token_type != TokenType::RightParenthesis {
+        let step_node: ASTNode = ast_declarations::parse_assignment(ast_data);
+        if ast_data.is_error() {
+            return for_node;
+        }
+        for_condition_node.right = structs::create_raw_pointer(step_node);
+    }
+    ast_data.error_function = "processFor".to_string();
+    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')' after for") == false {
+        ast_data.error_token = for_token;
+        return for_node;
+    }
+    ast_data.token_index += 1;
+
+    let body_node: ASTNode = parse_block(ast_data, &for_token, ASTNodeType::ForBody);
+    if ast_data.is_error() {
+        return for_node;
+    }
+
+    //Node_type: ForLoop
+    //Token:     for
+    //Left:      ForCondition
+    //Right:     ForBody
+    for_node.node_type = ASTNodeType::ForLoop;
+    for_node.token = structs::create_raw_pointer(for_token);
+    for_node.left = structs::create_raw_pointer(for_condition_node);
+    for_node.right = structs::create_raw_pointer(body_node);
+
+    return for_node;
+}
+
+// break; continue; break outer;
+pub fn process_loop_exit(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processLoopExit".to_string();
+
+    let mut exit_node: ASTNode = structs::get_default_node();
+
+    let exit_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return exit_node;
+    }
+
+    //Node_type: Break or Continue
+    //Token:     break or continue
+    //Left:      Identifier naming the loop's label, null for the innermost loop
+    if exit_token.token_type == TokenType::Break {
+        exit_node.node_type = ASTNodeType::Break;
+    } else {
+        exit_node.node_type = ASTNodeType::Continue;
+    }
+
+    let label_token: Token = ast_data.get_token();
+    if label_token.token_type == TokenType::Identifier {
+        let mut label_node: ASTNode = structs::get_default_node();
+        label_node.node_type = ASTNodeType::Identifier;
+        label_node.token = structs::create_raw_pointer(label_token);
+        exit_node.left = structs::create_raw_pointer(label_node);
+        if ast_data.increment_index() == false {
+            return exit_node;
+        }
+    }
+
+    if ast_data.expect_type(TokenType::Semicolon, &format!("Missing expected ';' after {}", exit_token.text)) == false {
+        ast_data.error_token = exit_token;
+        return exit_node;
+    }
+    ast_data.token_index += 1;
+
+    exit_node.token = structs::create_raw_pointer(exit_token);
+    return exit_node;
+}
+
+// outer: while (...) { }
+pub fn process_label(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processLabel".to_string();
+
+    let mut label_node: ASTNode = structs::get_default_node();
+
+    let label_token: Token = ast_data.get_token();
+    //skip the name and ':'
+    ast_data.token_index += 2;
+
+    let loop_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return label_node;
+    }
+
+    let loop_node: ASTNode = match loop_token.token_type {
+        TokenType::While => process_while(ast_data),
+        TokenType::For => process_for(ast_data),
+        TokenType::Do => process_do_while(ast_data),
+        _ => {
+            ast_data.error_detail = format!("label '{}' must be followed by a loop", label_token.text);
+            ast_data.error_token = label_token;
+            ast_data.error_code = AstError::UnexpectedType;
+            return label_node;
+        }
+    };
+    if ast_data.is_error() {
+        return label_node;
+    }
+
+    //Node_type: Label
+    //Token:     label name
+    //Left:      the loop it names
+    label_node.node_type = ASTNodeType::Label;
+    label_node.token = structs::create_raw_pointer(label_token);
+    label_node.left = structs::create_raw_pointer(loop_node);
+
+    return label_node;
+}
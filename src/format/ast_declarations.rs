@@ -0,0 +1,315 @@
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::format::ast_control_flow;
+use crate::format::ast_expressions;
+use crate::parse::parse_utils;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::Token;
+
+pub fn process_declaration(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processDeclaration".to_string();
+
+    let mut declaration_node: ASTNode = structs::get_default_node();
+
+    let type_node: ASTNode = ast_expressions::create_complex_declarations(ast_data);
+    if ast_data.is_error() {
+        return declaration_node;
+    }
+
+    //var name expected
+    let name_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return declaration_node;
+    }
+    if name_token.token_type != TokenType::Identifier {
+        ast_data.error_detail = "Missing expected variable name in declaration".to_string();
+        ast_data.error_token = name_token;
+        ast_data.error_code = AstError::InvalidDeclaration;
+        return declaration_node;
+    }
+    if ast_data.increment_index() == false {
+        return declaration_node;
+    }
+
+    //Node_type: Declaration
+    //Token:     variable name
+    //Left:      type node
+    //Right:     value, null if declared without one
+    declaration_node.node_type = ASTNodeType::Declaration;
+    declaration_node.is_const = type_node.is_const;
+    declaration_node.token = structs::create_raw_pointer(name_token);
+    declaration_node.left = structs::create_raw_pointer(type_node);
+
+    let token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return declaration_node;
+    }
+
+    //i32 value, bool ok = read_i32();
+    if token.token_type == TokenType::Comma {
+        return process_multiple_declaration(ast_data, declaration_node);
+    }
+
+    if token.token_type == TokenType::Equals {
+        ast_data.token_index += 1;
+
+        let value_node: ASTNode =
+            ast_expressions::parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+        if ast_data.is_error() {
+            return declaration_node;
+        }
+        if value_node.node_type == ASTNodeType::Invalid {
+            ast_data.error_detail = "Missing value after '=' in declaration".to_string();
+            ast_data.error_token = token;
+            ast_data.error_code = AstError::InvalidDeclaration;
+            return declaration_node;
+        }
+        declaration_node.right = structs::create_raw_pointer(value_node);
+    }
+
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after declaration") == false {
+        ast_data.error_token = token;
+        return declaration_node;
+    }
+    ast_data.token_index += 1;
+
+    return declaration_node;
+}
+
+/// A declaration outside any function, a package level var in Go
+pub fn process_global_declaration(ast_data: &mut ASTData) {
+    let first_token: Token = ast_data.get_token();
+    let declaration_node: ASTNode = process_declaration(ast_data);
+    if ast_data.is_error() {
+        return;
+    }
+    ast_data.error_function = "processGlobalDeclaration".to_string();
+
+    //Go can't assign several globals from one call outside a function
+    if declaration_node.node_type != ASTNodeType::Declaration {
+        ast_data.error_detail = "globals are declared one at a time, e.g. i32 a = 0; i32 b = 0;".to_string();
+        ast_data.error_token = first_token;
+        ast_data.error_code = AstError::InvalidDeclaration;
+        return;
+    }
+    ast_data.append_node(declaration_node);
+}
+
+fn process_multiple_declaration(ast_data: &mut ASTData, first_declaration: ASTNode) -> ASTNode {
+    ast_data.error_function = "processMultipleDeclaration".to_string();
+
+    //Node_type: MultipleDeclaration
+    //Token:     '='
+    //Children:  Declaration nodes without values
+    //Right:     value giving one result per declaration
+    let mut multiple_node: ASTNode = structs::get_default_node();
+    multiple_node.children.push(first_declaration);
+
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return multiple_node;
+        }
+
+        let token: Token = ast_data.get_token();
+        if token.token_type != TokenType::Comma {
+            break;
+        }
+        if ast_data.increment_index() == false {
+            return multiple_node;
+        }
+
+        let type_node: ASTNode = ast_expressions::create_complex_declarations(ast_data);
+        if ast_data.is_error() {
+            return multiple_node;
+        }
+        let name_token: Token = ast_data.get_token();
+        if ast_data.is_error() {
+            return multiple_node;
+        }
+        if name_token.token_type != TokenType::Identifier {
+            ast_data.error_detail = "Missing expected variable name in declaration".to_string();
+            ast_data.error_token = name_token;
+            ast_data.error_code = AstError::InvalidDeclaration;
+            return multiple_node;
+        }
+        if ast_data.increment_index() == false {
+            return multiple_node;
+        }
+
+        let mut declaration_node: ASTNode = structs::get_default_node();
+        declaration_node.node_type = ASTNodeType::Declaration;
+        declaration_node.is_const = type_node.is_const;
+        declaration_node.token = structs::create_raw_pointer(name_token);
+        declaration_node.left = structs::create_raw_pointer(type_node);
+        multiple_node.children.push(declaration_node);
+        ast_data.error_function = "processMultipleDeclaration".to_string();
+    }
+
+    let equals_token: Token = ast_data.get_token();
+    if ast_data.expect_type(TokenType::Equals, "Declaring more than one variable needs a value giving each of them, e.g. i32 value, bool ok = read_i32();") == false {
+        ast_data.error_token = equals_token;
+        return multiple_node;
+    }
+    if ast_data.increment_index() == false {
+        return multiple_node;
+    }
+
+    let value_node: ASTNode = ast_expressions::parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+    if ast_data.is_error() {
+        return multiple_node;
+    }
+    if value_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = "Missing value after '=' in declaration".to_string();
+        ast_data.error_token = equals_token;
+        ast_data.error_code = AstError::InvalidDeclaration;
+        return multiple_node;
+    }
+
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after declaration") == false {
+        ast_data.error_token = equals_token;
+        return multiple_node;
+    }
+    ast_data.token_index += 1;
+
+    multiple_node.node_type = ASTNodeType::MultipleDeclaration;
+    multiple_node.token = structs::create_raw_pointer(equals_token);
+    multiple_node.right = structs::create_raw_pointer(value_node);
+
+    return multiple_node;
+}
+
+pub fn process_assignment(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processAssignment".to_string();
+
+    let assignment_node: ASTNode = parse_assignment(ast_data);
+    if ast_data.is_error() {
+        return assignment_node;
+    }
+
+    let token: Token = ast_data.get_token();
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after assignment") == false {
+        ast_data.error_token = token;
+        return assignment_node;
+    }
+    ast_data.token_index += 1;
+
+    return assignment_node;
+}
+
+pub fn is_assignment_operator(token_type: i32) -> bool {
+    return token_type == TokenType::Equals
+        || token_type == TokenType::PlusEquals
+        || token_type == TokenType::MinusEquals
+        || token_type == TokenType::MultiplyEquals
+        || token_type == TokenType::DivideEquals
+        || token_type == TokenType::ModulusEquals;
+}
+
+/// An assignment, i++ or a call without its ';', also used for a for loop's step
+pub fn parse_assignment(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "parseAssignment".to_string();
+
+    let mut assignment_node: ASTNode = structs::get_default_node();
+
+    let target_node: ASTNode = ast_expressions::parse_primary_any(ast_data);
+    if ast_data.is_error() {
+        return assignment_node;
+    }
+
+    let operator_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return assignment_node;
+    }
+
+    //a call on its own, e.g. update(world);
+    let is_statement_end: bool = operator_token.token_type == TokenType::Semicolon || operator_token.token_type == TokenType::RightParenthesis;
+    if target_node.node_type == ASTNodeType::FunctionCall && is_statement_end {
+        return target_node;
+    }
+
+    //Node_type: Assignment
+    //Token:     assignment operator, ++ or --
+    //Left:      variable being assigned to
+    //Right:     value, null for ++ and --
+    if operator_token.token_type == TokenType::PlusPlus || operator_token.token_type == TokenType::MinusMinus {
+        ast_data.token_index += 1;
+        assignment_node.node_type = ASTNodeType::Assignment;
+        assignment_node.token = structs::create_raw_pointer(operator_token);
+        assignment_node.left = structs::create_raw_pointer(target_node);
+        return assignment_node;
+    }
+
+    if is_assignment_operator(operator_token.token_type) == false {
+        ast_data.error_detail = "Missing expected '=' in assignment".to_string();
+        ast_data.error_token = operator_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+
+        //"retrun 5;" or "whlie (x) {" start like an assignment or a call
+        let is_name: bool = target_node.node_type == ASTNodeType::Identifier || target_node.node_type == ASTNodeType::FunctionCall;
+        if is_name && target_node.token.is_null() == false {
+            unsafe {
+                let name_token: Token = (*target_node.token).clone();
+                let suggestion: String = parse_utils::get_keyword_suggestion(&name_token.text);
+                if suggestion.is_empty() == false {
+                    ast_data.error_detail = format!("unexpected '{}'{}", name_token.text, suggestion);
+                    ast_data.error_token = name_token;
+                }
+            }
+        }
+        return assignment_node;
+    }
+    if ast_data.increment_index() == false {
+        return assignment_node;
+    }
+
+    let value_node: ASTNode =
+        ast_expressions::parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+    if ast_data.is_error() {
+        return assignment_node;
+    }
+    if value_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = "Missing value after '=' in assignment".to_string();
+        ast_data.error_token = operator_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return assignment_node;
+    }
+
+    assignment_node.node_type = ASTNodeType::Assignment;
+    assignment_node.token = structs::create_raw_pointer(operator_token);
+    assignment_node.left = structs::create_raw_pointer(target_node);
+    assignment_node.right = structs::create_raw_pointer(value_node);
+
+    return assignment_node;
+}
+
+/// Statements starting with a name: a declaration of a user type, a loop label, an assignment or a call
+pub fn process_identifier_statement(ast_data: &mut ASTData) -> ASTNode {
+    let next_index: usize = ast_data.token_index + 1;
+    if next_index < ast_data.token_list.len() {
+        let next_token: &Token = &ast_data.token_list[next_index];
+        if next_token.token_type == TokenType::Identifier {
+            return process_declaration(ast_data);
+        }
+        if next_token.token_type == TokenType::Colon {
+            return ast_control_flow::process_label(ast_data);
+        }
+    }
+    //Point[] points;
+    let saved_index: usize = ast_data.token_index;
+    ast_data.token_index += 1;
+    let is_array_type: bool = ast_expressions::is_array_suffix(ast_data);
+    ast_data.token_index = saved_index;
+    if is_array_type {
+        return process_declaration(ast_data);
+    }
+    return process_assignment(ast_data);
+}
@@ -1,330 +1,896 @@
-use crate::core::debugging;
-use crate::core::enums;
-use crate::core::errors;
-use crate::core::structs;
-use crate::core::token_utils;
-use crate::format::ast_utils;
-use enums::ASTNodeType;
-use enums::LoopResult;
-use enums::TokenType;
-use errors::AstError;
-use structs::ASTData;
-use structs::ASTNode;
-use structs::Token;
-
-fn parse_single_parameter(ast_data: &mut ASTData) -> ASTNode {
-    let type_node: ASTNode = create_complex_declarations(ast_data);
-
-    // Now parse the parameter name
-    let name_token: Token = ast_data.get_token();
-    if ast_data.is_error() {
-        return type_node;
-    }
-
-    if name_token.token_type != TokenType::Identifier {
-        ast_data.error_token = name_token.clone();
-        ast_data.error_detail = "Expected identifier for parameter name".to_string();
-        return type_node;
-    }
-    ast_data.token_index += 1;
-
-    let mut parameter_node: ASTNode = structs::get_default_node();
-
-    parameter_node.node_type = ASTNodeType::Parameter;
-    let name_token_pointer: *mut Token = structs::create_raw_pointer(name_token);
-    parameter_node.token = name_token_pointer;
-    let type_node_pointer: *mut ASTNode = structs::create_raw_pointer(type_node);
-    parameter_node.left = type_node_pointer;
-
-    return parameter_node;
-}
-
-pub fn fill_parameters(ast_data: &mut ASTData) -> ASTNode {
-    ast_data.error_function = "fillParameters".to_string();
-
-    let child_list: Vec<ASTNode> = Vec::new();
-
-    let mut parameters_node: ASTNode = structs::get_default_node();
-    parameters_node.children = child_list;
-    parameters_node.node_type = ASTNodeType::Parameters;
-
-    let mut while_count: usize = 0;
-
-    while ast_data.token_index_in_bounds() {
-        if debugging::is_infinite_while(&mut while_count, 1000) {
-            ast_data.error_code = AstError::InfiniteWhileLoop;
-            return parameters_node;
-        }
-
-        let index_before: usize = ast_data.token_index;
-
-        let token: Token = ast_data.get_token();
-
-        if token.token_type == TokenType::RightParenthesis {
-            break;
-        }
-        if token.token_type != TokenType::Comma {
-            //print("Add parameter\n", .{});
-            let parameter_node: ASTNode = parse_single_parameter(ast_data);
-            parameters_node.children.push(parameter_node);
-        }
-
-        if index_before == ast_data.token_index {
-            ast_data.token_index += 1;
-        }
-    }
-
-    return parameters_node;
-}
-
-fn complex_declaration_inner_loop(
-    ast_data: &mut ASTData,
-    final_node: &mut ASTNode,
-    token: Token,
-) -> i32 {
-    if token.text == "const" {
-        final_node.is_const = true;
-        return LoopResult::Continue;
-    }
-
-    if token.text == "*" {
-        if ast_data.increment_index() == false {
-            return LoopResult::Break;
-        }
-
-        let mut pointer_node: ASTNode = structs::get_default_node();
-        pointer_node.node_type = ASTNodeType::Pointer;
-        let token_pointer: *mut Token = structs::create_raw_pointer(token);
-        pointer_node.token = token_pointer;
-        pointer_node.left = final_node; // Point to the OLD final_node
-
-        // Now reassign the pointer itself
-        *final_node = pointer_node; // final_node now points to pointer_node
-        return LoopResult::Continue;
-    }
-
-    //if token.text == "[" {
-
-    //  ast_data.increment_index();
-
-    //let next_token:Token = ast_data.get_token();
-    //if ast_data.is_error() {
-    //return LoopResult::Break;
-    //}
-
-    //let array_size:usize = 0;
-
-    //if next_token.token_type != TokenType::RightSquareBracket {
-
-    //if next_token.token_type != TokenType::IntegerValue {
-    //ast_data.error_detail = "Expected ']' or array size after '[' in array".to_string();
-    //ast_data.error_token = next_token;
-    //ast_data.error_code = AstError::MissingExpectedType;
-    //return LoopResult::Break;
-    //}
-    //const size = std.fmt.parseInt(usize, next_token.Text, 10) catch {
-    //return AstError.Missing_Expected_Type;
-    //};
-    //array_size = size;
-    //const close2:Token = try token_utils_script.getNextToken(ast_data);
-    //if (close2.Type != TokenType.RightSquareBracket) {
-    //ast_data.setErrorData("Expected ']' or after '[' in array", next_token);
-    //return AstError.Missing_Expected_Type;
-    //  }
-    //}
-
-    //if ast_data.increment_index() {
-    // return LoopResult::Break;
-    //}
-
-    // var array_node:*ASTNode = try ast_node_utils_script.createDefaultAstNode(allocator);
-    //array_node.node_type = ASTNodeType.Array;
-    //array_node.token = token;
-    //array_node.left = final_node.*;  // Point to the OLD final_node
-    //if (array_size != null) {
-    //array_node.size = array_size.?;
-    //}
-
-    // Now reassign the pointer itself
-    //final_node.* = array_node;  // final_node now points to array_node
-    //return LoopResult::Continue;
-    //}
-
-    return LoopResult::None;
-}
-
-fn get_base_type(node: *mut ASTNode) -> *mut ASTNode {
-    unsafe {
-        if (*node).left.is_null() {
-            return node.clone();
-        }
-        return get_base_type((*node).left);
-    }
-}
-
-pub fn create_complex_declarations(ast_data: &mut ASTData) -> ASTNode {
-    ast_data.error_function = "createComplexDeclarations".to_string();
-
-    let mut final_node: ASTNode = structs::get_default_node();
-    let mut while_count: usize = 0;
-
-    let first_token: Token = ast_data.get_token();
-
-    while ast_data.token_index_in_bounds() {
-        if debugging::is_infinite_while(&mut while_count, 1000) {
-            ast_data.error_code = AstError::InfiniteWhileLoop;
-            return final_node;
-        }
-
-        let token: Token = ast_data.get_token();
-        if ast_data.is_error() {
-            return final_node;
-        }
-
-        // Handle inner loop declarations like array size brackets
-        let loop_result: i32 =
-            complex_declaration_inner_loop(ast_data, &mut final_node, token.clone());
-        if loop_result == LoopResult::Continue {
-            continue;
-        }
-
-        // If the token is a base type (e.g., "i32"), attach it at the innermost level
-        if token_utils::is_type_token(token.clone()) {
-            if ast_data.increment_index() == false {
-                return final_node;
-            }
-
-            let mut type_node: ASTNode = structs::get_default_node();
-            type_node.node_type = ASTNodeType::VarType;
-            type_node.token = Box::into_raw(Box::new(token));
-
-            if final_node.node_type == ASTNodeType::Invalid {
-                final_node = type_node;
-                break;
-            }
-
-            // Traverse to the leftmost node and attach the type
-            let mut innermost: *mut ASTNode = &mut final_node as *mut ASTNode;
-            let mut inner_while_count: usize = 0;
-
-            unsafe {
-                while !(*innermost).left.is_null() {
-                    if debugging::is_infinite_while(&mut inner_while_count, 1000) {
-                        ast_data.error_code = AstError::InfiniteWhileLoop;
-                        return final_node;
-                    }
-                    innermost = (*innermost).left;
-                }
-                (*innermost).left = Box::into_raw(Box::new(type_node));
-            }
-            break;
-        }
-
-        break;
-    }
-
-    if final_node.node_type == ASTNodeType::Invalid {
-        ast_data.error_token = first_token;
-        ast_data.error_detail = "final node is null in createComplexDeclarations".to_string();
-        ast_data.error_code = AstError::NullType;
-        return final_node;
-    }
-
-    return final_node;
-}
-
-pub fn parsePrimaryAny(ast_data: &mut ASTData) -> ASTNode {
-    ast_data.error_function = "parsePrimaryAny".to_string();
-
-    if ast_data.token_index_in_bounds() == false {
-        ast_data.error_code = AstError::UnexpectedEndOfFile;
-        return structs::get_default_node();
-    }
-
-    let token: Token = ast_data.get_token();
-    let mut node: ASTNode = structs::get_default_node();
-
-    match token.token_type {
-        TokenType::False | TokenType::True => {
-            node.node_type = ASTNodeType::BoolLiteral;
-            node.token = structs::create_raw_pointer(token);
-        }
-        TokenType::IntegerValue => {
-            node.node_type = ASTNodeType::StringLiteral;
-            node.token = structs::create_raw_pointer(token);
-        }
-        //TokenType.Identifier => try parseProcessIdentifier(allocator, ast_data, node, token),
-        TokenType::StringValue => {
-            node.node_type = ASTNodeType::StringLiteral;
-            node.token = structs::create_raw_pointer(token);
-        }
-        TokenType::CharValue => {
-            node.node_type = ASTNodeType::CharLiteral;
-            node.token = structs::create_raw_pointer(token);
-        }
-        //TokenType.LeftParenthesis => return ProcessParsePrimaryLeftParenthesis(ast_data, node_type, allocator),
-        TokenType::RightParenthesis => return node,
-        //TokenType.And => return ProcessReference(ast_data, token, allocator),
-        //TokenType.Minus => try parseMinus(allocator, ast_data, node, token),
-        _ => {
-            ast_data.error_detail = "Unexpected type in expression, {token.Type}".to_string();
-            ast_data.error_token = token;
-            ast_data.error_code = AstError::UnexpectedType;
-            return node;
-        }
-    }
-
-    ast_data.token_index += 1;
-    return node;
-}
-
-pub fn parse_binary_expression_any(ast_data:&mut ASTData, min_prec:usize, node_type:i32) -> ASTNode {
-
-    ast_data.error_function = "parseBinaryExprAny".to_string();
-    let mut left: ASTNode = parsePrimaryAny(ast_data);
-
-    let mut white_count: usize = 0;
-    let max_loops: usize = 1000;
-
-    while ast_data.token_index_in_bounds() {
-        
-        if debugging::is_infinite_while(&mut white_count, max_loops) {
-            return left;
-        }
-
-        let operator_token: Token = ast_data.get_token();
-
-        if token_utils::is_binary_operator_bool(operator_token.token_type) == false {
-            break;
-        }
-
-        let precedence: usize = token_utils::get_precedence_bool(operator_token.token_type);
-
-        if precedence < min_prec {
-            break;
-        }
-
-        ast_data.token_index += 1; // move past operator
-        let right: ASTNode = parse_binary_expression_any(ast_data, precedence + 1, node_type);
-        if ast_data.is_error() {
-            return left;
-        }
-
-        if right.node_type == ASTNodeType::Invalid {
-            ast_data.error_detail = "Missing value after equation symbol".to_string();
-            ast_data.error_token = operator_token;
-            ast_data.error_code = AstError::UnexpectedType;
-            return left;
-        }
-
-        let mut new_node: ASTNode = structs::get_default_node();
-
-        new_node.node_type = node_type;
-        new_node.token = structs::create_raw_pointer(operator_token);
-        new_node.left = structs::create_raw_pointer(left);
-        new_node.right = structs::create_raw_pointer(right);
-
-        left = new_node;
-    }
-    return left;
-}
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::format::ast_utils;
+use enums::ASTNodeType;
+use enums::LoopResult;
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::Token;
+
+fn parse_single_parameter(ast_data: &mut ASTData) -> ASTNode {
+    let type_node: ASTNode = create_complex_declarations(ast_data);
+
+    // Now parse the parameter name
+    let name_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return type_node;
+    }
+
+    if name_token.token_type != TokenType::Identifier {
+        ast_data.error_token = name_token.clone();
+        ast_data.error_detail = "Expected identifier for parameter name".to_string();
+        return type_node;
+    }
+    ast_data.token_index += 1;
+
+    let mut parameter_node: ASTNode = structs::get_default_node();
+
+    parameter_node.node_type = ASTNodeType::Parameter;
+    let name_token_pointer: *mut Token = structs::create_raw_pointer(name_token);
+    parameter_node.token = name_token_pointer;
+    let type_node_pointer: *mut ASTNode = structs::create_raw_pointer(type_node);
+    parameter_node.left = type_node_pointer;
+
+    return parameter_node;
+}
+
+pub fn fill_parameters(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "fillParameters".to_string();
+
+    let child_list: Vec<ASTNode> = Vec::new();
+
+    let mut parameters_node: ASTNode = structs::get_default_node();
+    parameters_node.children = child_list;
+    parameters_node.node_type = ASTNodeType::Parameters;
+
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return parameters_node;
+        }
+
+        let index_before: usize = ast_data.token_index;
+
+        let token: Token = ast_data.get_token();
+
+        if token.token_type == TokenType::RightParenthesis {
+            break;
+        }
+        if token.token_type != TokenType::Comma {
+            //print("Add parameter\n", .{});
+            let parameter_node: ASTNode = parse_single_parameter(ast_data);
+            parameters_node.children.push(parameter_node);
+        }
+
+        if index_before == ast_data.token_index {
+            ast_data.token_index += 1;
+        }
+    }
+
+    return parameters_node;
+}
+
+fn complex_declaration_inner_loop(
+    ast_data: &mut ASTData,
+    final_node: &mut ASTNode,
+    token: Token,
+) -> i32 {
+    if token.text == "const" {
+        if ast_data.increment_index() == false {
+            return LoopResult::Break;
+        }
+        final_node.is_const = true;
+        return LoopResult::Continue;
+    }
+
+    if token.text == "*" {
+        if ast_data.increment_index() == false {
+            return LoopResult::Break;
+        }
+
+        let mut pointer_node: ASTNode = structs::get_default_node();
+        pointer_node.node_type = ASTNodeType::Pointer;
+        let token_pointer: *mut Token = structs::create_raw_pointer(token);
+        pointer_node.token = token_pointer;
+        pointer_node.is_const = final_node.is_const;
+        if final_node.node_type != ASTNodeType::Invalid {
+            pointer_node.left = structs::create_raw_pointer(final_node.clone()); // Point to the OLD final_node
+        }
+
+        // Now reassign the pointer itself
+        *final_node = pointer_node; // final_node now points to pointer_node
+        return LoopResult::Continue;
+    }
+
+    //if token.text == "[" {
+
+    //  ast_data.increment_index();
+
+    //let next_token:Token = ast_data.get_token();
+    //if ast_data.is_error() {
+    //return LoopResult::Break;
+    //}
+
+    //let array_size:usize = 0;
+
+    //if next_token.token_type != TokenType::RightSquareBracket {
+
+    //if next_token.token_type != TokenType::IntegerValue {
+    //ast_data.error_detail = "Expected ']' or array size after '[' in array".to_string();
+    //ast_data.error_token = next_token;
+    //ast_data.error_code = AstError::MissingExpectedType;
+    //return LoopResult::Break;
+    //}
+    //const size = std.fmt.parseInt(usize, next_token.Text, 10) catch {
+    //return AstError.Missing_Expected_Type;
+    //};
+    //array_size = size;
+    //const close2:Token = try token_utils_script.getNextToken(ast_data);
+    //if (close2.Type != TokenType.RightSquareBracket) {
+    //ast_data.setErrorData("Expected ']' or after '[' in array", next_token);
+    //return AstError.Missing_Expected_Type;
+    //  }
+    //}
+
+    //if ast_data.increment_index() {
+    // return LoopResult::Break;
+    //}
+
+    // var array_node:*ASTNode = try ast_node_utils_script.createDefaultAstNode(allocator);
+    //array_node.node_type = ASTNodeType.Array;
+    //array_node.token = token;
+    //array_node.left = final_node.*;  // Point to the OLD final_node
+    //if (array_size != null) {
+    //array_node.size = array_size.?;
+    //}
+
+    // Now reassign the pointer itself
+    //final_node.* = array_node;  // final_node now points to array_node
+    //return LoopResult::Continue;
+    //}
+
+    return LoopResult::None;
+}
+
+#[allow(dead_code)]
+fn get_base_type(node: *mut ASTNode) -> *mut ASTNode {
+    unsafe {
+        if (*node).left.is_null() {
+            return node.clone();
+        }
+        return get_base_type((*node).left);
+    }
+}
+
+// fn(i32, i32) -> i32, a missing '->' means the function returns void
+fn parse_function_type(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "parseFunctionType".to_string();
+
+    let mut function_type_node: ASTNode = structs::get_default_node();
+
+    let fn_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return function_type_node;
+    }
+    if ast_data.expect_type(TokenType::LeftParenthesis, "Missing expected '(' in function type") == false {
+        ast_data.error_token = fn_token;
+        return function_type_node;
+    }
+    if ast_data.increment_index() == false {
+        return function_type_node;
+    }
+
+    let mut parameters_node: ASTNode = structs::get_default_node();
+    parameters_node.node_type = ASTNodeType::Parameters;
+
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return function_type_node;
+        }
+
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::RightParenthesis {
+            break;
+        }
+        if token.token_type == TokenType::Comma {
+            ast_data.token_index += 1;
+            continue;
+        }
+
+        let parameter_type_node: ASTNode = create_complex_declarations(ast_data);
+        if ast_data.is_error() {
+            return function_type_node;
+        }
+        parameters_node.children.push(parameter_type_node);
+        ast_data.error_function = "parseFunctionType".to_string();
+    }
+
+    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')' in function type") == false {
+        ast_data.error_token = fn_token;
+        return function_type_node;
+    }
+    if ast_data.increment_index() == false {
+        return function_type_node;
+    }
+
+    //Node_type: FunctionType
+    //Token:     fn
+    //Middle:    parameter types
+    //Right:     return type, null for void
+    function_type_node.node_type = ASTNodeType::FunctionType;
+    function_type_node.token = structs::create_raw_pointer(fn_token);
+    function_type_node.middle = structs::create_raw_pointer(parameters_node);
+
+    let token: Token = ast_data.get_token();
+    if token.token_type == TokenType::Arrow {
+        if ast_data.increment_index() == false {
+            return function_type_node;
+        }
+        let return_type_node: ASTNode = create_complex_declarations(ast_data);
+        if ast_data.is_error() {
+            return function_type_node;
+        }
+        function_type_node.right = structs::create_raw_pointer(return_type_node);
+    }
+
+    return function_type_node;
+}
+
+// chan<i32>, a channel carrying values of one type
+fn parse_channel_type(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "parseChannelType".to_string();
+
+    let mut channel_node: ASTNode = structs::get_default_node();
+
+    let chan_token: Token = ast_data.get_token();
+    if ast_data.increment_index() == false {
+        return channel_node;
+    }
+    if ast_data.expect_type(TokenType::LessThan, "Missing expected '<' in channel type, e.g. chan<i32>") == false {
+        ast_data.error_token = chan_token;
+        return channel_node;
+    }
+    if ast_data.increment_index() == false {
+        return channel_node;
+    }
+
+    let element_type_node: ASTNode = create_complex_declarations(ast_data);
+    if ast_data.is_error() {
+        return channel_node;
+    }
+    ast_data.error_function = "parseChannelType".to_string();
+
+    if ast_data.expect_type(TokenType::GreaterThan, "Missing expected '>' in channel type") == false {
+        ast_data.error_token = chan_token;
+        return channel_node;
+    }
+    if ast_data.increment_index() == false {
+        return channel_node;
+    }
+
+    //Node_type: Channel
+    //Token:     chan
+    //Left:      element type
+    channel_node.node_type = ASTNodeType::Channel;
+    channel_node.token = structs::create_raw_pointer(chan_token);
+    channel_node.left = structs::create_raw_pointer(element_type_node);
+
+    return channel_node;
+}
+
+pub fn create_complex_declarations(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "createComplexDeclarations".to_string();
+
+    let mut final_node: ASTNode = structs::get_default_node();
+    let mut while_count: usize = 0;
+
+    let first_token: Token = ast_data.get_token();
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return final_node;
+        }
+
+        let token: Token = ast_data.get_token();
+        if ast_data.is_error() {
+            return final_node;
+        }
+
+        // Handle inner loop declarations like array size brackets
+        let loop_result: i32 =
+            complex_declaration_inner_loop(ast_data, &mut final_node, token.clone());
+        if loop_result == LoopResult::Continue {
+            continue;
+        }
+
+        let is_function_type: bool = token.token_type == TokenType::Fn;
+        let is_channel_type: bool = token.token_type == TokenType::Chan;
+
+        // If the token is a base type (e.g., "i32"), attach it at the innermost level
+        if is_function_type || is_channel_type || token_utils::is_type_token(token.clone()) {
+            let mut type_node: ASTNode;
+
+            if is_function_type {
+                type_node = parse_function_type(ast_data);
+                if ast_data.is_error() {
+                    return final_node;
+                }
+            } else if is_channel_type {
+                type_node = parse_channel_type(ast_data);
+                if ast_data.is_error() {
+                    return final_node;
+                }
+            } else {
+                if ast_data.increment_index() == false {
+                    return final_node;
+                }
+                type_node = structs::get_default_node();
+                type_node.node_type = ASTNodeType::VarType;
+                type_node.token = Box::into_raw(Box::new(token));
+            }
+
+            if final_node.node_type == ASTNodeType::Invalid {
+                type_node.is_const = final_node.is_const;
+                final_node = type_node;
+                break;
+            }
+
+            // Traverse to the leftmost node and attach the type
+            let mut innermost: *mut ASTNode = &mut final_node as *mut ASTNode;
+            let mut inner_while_count: usize = 0;
+
+            unsafe {
+                while !(*innermost).left.is_null() {
+                    if debugging::is_infinite_while(&mut inner_while_count, 1000) {
+                        ast_data.error_code = AstError::InfiniteWhileLoop;
+                        return final_node;
+                    }
+                    innermost = (*innermost).left;
+                }
+                (*innermost).left = Box::into_raw(Box::new(type_node));
+            }
+            break;
+        }
+
+        break;
+    }
+
+    if final_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_token = first_token;
+        ast_data.error_detail = "final node is null in createComplexDeclarations".to_string();
+        ast_data.error_code = AstError::NullType;
+        return final_node;
+    }
+
+    //string[] or *i32[], the array wraps everything before it
+    while is_array_suffix(ast_data) {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return final_node;
+        }
+        let bracket_token: Token = ast_data.get_token();
+        ast_data.token_index += 2;
+
+        //Node_type: Array
+        //Token:     '['
+        //Left:      element type
+        let mut array_node: ASTNode = structs::get_default_node();
+        array_node.node_type = ASTNodeType::Array;
+        array_node.token = structs::create_raw_pointer(bracket_token);
+        array_node.is_const = final_node.is_const;
+        array_node.left = structs::create_raw_pointer(final_node);
+        final_node = array_node;
+    }
+
+    return final_node;
+}
+
+pub fn is_array_suffix(ast_data: &ASTData) -> bool {
+    let next_index: usize = ast_data.token_index + 1;
+    if next_index >= ast_data.token_list.len() {
+        return false;
+    }
+    return ast_data.token_list[ast_data.token_index].token_type == TokenType::LeftSquareBracket
+        && ast_data.token_list[next_index].token_type == TokenType::RightSquareBracket;
+}
+
+fn parse_cast(ast_data: &mut ASTData, left_parenthesis_token: Token) -> ASTNode {
+    ast_data.error_function = "parseCast".to_string();
+
+    let mut cast_node: ASTNode = structs::get_default_node();
+
+    //skip '('
+    if ast_data.increment_index() == false {
+        return cast_node;
+    }
+    let type_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return cast_node;
+    }
+
+    let mut type_node: ASTNode = structs::get_default_node();
+    type_node.node_type = ASTNodeType::VarType;
+    type_node.token = structs::create_raw_pointer(type_token.clone());
+
+    //skip type
+    if ast_data.increment_index() == false {
+        return cast_node;
+    }
+    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')' in cast") == false {
+        ast_data.error_token = left_parenthesis_token;
+        return cast_node;
+    }
+    if ast_data.increment_index() == false {
+        return cast_node;
+    }
+
+    let value_node: ASTNode = parse_primary_any(ast_data);
+    if ast_data.is_error() {
+        return cast_node;
+    }
+    if value_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = "Missing value after cast".to_string();
+        ast_data.error_token = type_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return cast_node;
+    }
+
+    //Node_type: Cast
+    //Token:     type token
+    //Left:      type node
+    //Right:     value being cast
+    cast_node.node_type = ASTNodeType::Cast;
+    cast_node.token = structs::create_raw_pointer(type_token);
+    cast_node.left = structs::create_raw_pointer(type_node);
+    cast_node.right = structs::create_raw_pointer(value_node);
+    return cast_node;
+}
+
+fn is_cast(ast_data: &ASTData) -> bool {
+    let type_index: usize = ast_data.token_index + 1;
+    let close_index: usize = ast_data.token_index + 2;
+    if close_index >= ast_data.token_list.len() {
+        return false;
+    }
+    let type_token: &Token = &ast_data.token_list[type_index];
+    if token_utils::is_var_type(type_token.token_type) == false {
+        return false;
+    }
+    if type_token.token_type == TokenType::Void {
+        return false;
+    }
+    return ast_data.token_list[close_index].token_type == TokenType::RightParenthesis;
+}
+
+fn parse_primary_left_parenthesis(ast_data: &mut ASTData, token: Token) -> ASTNode {
+    if is_cast(ast_data) {
+        return parse_cast(ast_data, token);
+    }
+
+    //skip '('
+    if ast_data.increment_index() == false {
+        return structs::get_default_node();
+    }
+    let inner_node: ASTNode = parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+    if ast_data.is_error() {
+        return inner_node;
+    }
+    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')' in expression") == false {
+        ast_data.error_token = token;
+        return inner_node;
+    }
+    ast_data.token_index += 1;
+    return inner_node;
+}
+
+fn parse_minus(ast_data: &mut ASTData, token: Token) -> ASTNode {
+    let mut minus_node: ASTNode = structs::get_default_node();
+
+    //skip '-'
+    if ast_data.increment_index() == false {
+        return minus_node;
+    }
+    let value_node: ASTNode = parse_primary_any(ast_data);
+    if ast_data.is_error() {
+        return minus_node;
+    }
+    if value_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = "Missing value after '-'".to_string();
+        ast_data.error_token = token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return minus_node;
+    }
+
+    minus_node.node_type = ASTNodeType::Minus;
+    minus_node.token = structs::create_raw_pointer(token);
+    minus_node.left = structs::create_raw_pointer(value_node);
+    return minus_node;
+}
+
+// Calls and field accesses following a value, e.g. shapes.area(2)
+fn parse_postfix(ast_data: &mut ASTData, value_node: ASTNode) -> ASTNode {
+    let mut node: ASTNode = value_node;
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return node;
+        }
+
+        let token: Token = ast_data.get_token();
+
+        if token.token_type == TokenType::LeftParenthesis {
+            node = parse_function_call(ast_data, node);
+            if ast_data.is_error() {
+                return node;
+            }
+            continue;
+        }
+        //max<i32>(a, b)
+        if token.token_type == TokenType::LessThan && is_generic_call(ast_data) {
+            let type_arguments_node: ASTNode = parse_type_arguments(ast_data);
+            if ast_data.is_error() {
+                return node;
+            }
+            node = parse_function_call(ast_data, node);
+            if ast_data.is_error() {
+                return node;
+            }
+            node.middle = structs::create_raw_pointer(type_arguments_node);
+            continue;
+        }
+        if token.token_type == TokenType::LeftSquareBracket {
+            node = parse_array_access(ast_data, node);
+            if ast_data.is_error() {
+                return node;
+            }
+            continue;
+        }
+        if token.token_type == TokenType::FullStop {
+            if ast_data.increment_index() == false {
+                return node;
+            }
+            let field_token: Token = ast_data.get_token();
+            if field_token.token_type != TokenType::Identifier {
+                ast_data.error_detail = "Missing expected field name after '.'".to_string();
+                ast_data.error_token = field_token;
+                ast_data.error_code = AstError::MissingExpectedType;
+                return node;
+            }
+            ast_data.token_index += 1;
+
+            //Node_type: FieldAccess
+            //Token:     field name
+            //Left:      struct value
+            let mut field_node: ASTNode = structs::get_default_node();
+            field_node.node_type = ASTNodeType::FieldAccess;
+            field_node.token = structs::create_raw_pointer(field_token);
+            field_node.left = structs::create_raw_pointer(node);
+            node = field_node;
+            continue;
+        }
+        break;
+    }
+    return node;
+}
+
+fn parse_array_access(ast_data: &mut ASTData, value_node: ASTNode) -> ASTNode {
+    ast_data.error_function = "parseArrayAccess".to_string();
+
+    let bracket_token: Token = ast_data.get_token();
+
+    //skip '['
+    if ast_data.increment_index() == false {
+        return value_node;
+    }
+
+    let index_node: ASTNode = parse_binary_expression_any(ast_data, 0, ASTNodeType::BinaryExpression);
+    if ast_data.is_error() {
+        return value_node;
+    }
+    if index_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = "Missing index inside '[]'".to_string();
+        ast_data.error_token = bracket_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return value_node;
+    }
+
+    if ast_data.expect_type(TokenType::RightSquareBracket, "Missing expected ']' after index") == false {
+        ast_data.error_token = bracket_token;
+        return value_node;
+    }
+    ast_data.token_index += 1;
+
+    //Node_type: ArrayAccess
+    //Token:     '['
+    //Left:      string or array being indexed
+    //Right:     index
+    let mut access_node: ASTNode = structs::get_default_node();
+    access_node.node_type = ASTNodeType::ArrayAccess;
+    access_node.token = structs::create_raw_pointer(bracket_token);
+    access_node.left = structs::create_raw_pointer(value_node);
+    access_node.right = structs::create_raw_pointer(index_node);
+    return access_node;
+}
+
+/// Looks past a '<' for "types>(" so a generic call isn't read as a comparison
+fn is_generic_call(ast_data: &ASTData) -> bool {
+    let token_count: usize = ast_data.token_list.len();
+    let mut i: usize = ast_data.token_index + 1;
+
+    while i < token_count {
+        let token_type: i32 = ast_data.token_list[i].token_type;
+        if token_type == TokenType::GreaterThan {
+            if i == ast_data.token_index + 1 || i + 1 >= token_count {
+                return false;
+            }
+            return ast_data.token_list[i + 1].token_type == TokenType::LeftParenthesis;
+        }
+        let is_type_part: bool = token_utils::is_var_type(token_type)
+            || token_type == TokenType::Identifier
+            || token_type == TokenType::Multiply
+            || token_type == TokenType::Comma
+            || token_type == TokenType::Fn
+            || token_type == TokenType::Chan
+            || token_type == TokenType::LeftParenthesis
+            || token_type == TokenType::RightParenthesis
+            || token_type == TokenType::LeftSquareBracket
+            || token_type == TokenType::RightSquareBracket
+            || token_type == TokenType::Arrow;
+        if is_type_part == false {
+            return false;
+        }
+        i += 1;
+    }
+    return false;
+}
+
+fn parse_type_arguments(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "parseTypeArguments".to_string();
+
+    //Node_type: TypeArguments
+    //Token:     '<'
+    //Children:  type nodes
+    let open_token: Token = ast_data.get_token();
+    let mut type_arguments_node: ASTNode = structs::get_default_node();
+    type_arguments_node.node_type = ASTNodeType::TypeArguments;
+    type_arguments_node.token = structs::create_raw_pointer(open_token.clone());
+
+    //skip '<'
+    if ast_data.increment_index() == false {
+        return type_arguments_node;
+    }
+
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return type_arguments_node;
+        }
+
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::GreaterThan {
+            break;
+        }
+        if token.token_type == TokenType::Comma {
+            ast_data.token_index += 1;
+            continue;
+        }
+
+        let type_node: ASTNode = create_complex_declarations(ast_data);
+        if ast_data.is_error() {
+            return type_arguments_node;
+        }
+        type_arguments_node.children.push(type_node);
+    }
+
+    if ast_data.expect_type(TokenType::GreaterThan, "Missing expected '>' after type arguments") == false {
+        ast_data.error_token = open_token;
+        return type_arguments_node;
+    }
+    ast_data.token_index += 1;
+
+    return type_arguments_node;
+}
+
+fn parse_function_call(ast_data: &mut ASTData, function_node: ASTNode) -> ASTNode {
+    ast_data.error_function = "parseFunctionCall".to_string();
+
+    //Node_type: FunctionCall
+    //Token:     function name
+    //Left:      the function being called, a name or a function value
+    //Middle:    TypeArguments for a generic call, otherwise null
+    //Children:  arguments
+    let mut call_node: ASTNode = structs::get_default_node();
+    call_node.node_type = ASTNodeType::FunctionCall;
+    call_node.token = function_node.token;
+    call_node.left = structs::create_raw_pointer(function_node);
+
+    //skip '('
+    if ast_data.increment_index() == false {
+        return call_node;
+    }
+
+    ast_utils::fill_node_in_brackets(ast_data, &mut call_node, ASTNodeType::BinaryExpression);
+    if ast_data.is_error() {
+        return call_node;
+    }
+
+    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')' after function call") == false {
+        return call_node;
+    }
+    ast_data.token_index += 1;
+    return call_node;
+}
+
+pub fn parse_primary_any(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "parsePrimaryAny".to_string();
+
+    if ast_data.token_index_in_bounds() == false {
+        ast_data.error_code = AstError::UnexpectedEndOfFile;
+        return structs::get_default_node();
+    }
+
+    let token: Token = ast_data.get_token();
+    let mut node: ASTNode = structs::get_default_node();
+
+    match token.token_type {
+        TokenType::False | TokenType::True => {
+            node.node_type = ASTNodeType::BoolLiteral;
+            node.token = structs::create_raw_pointer(token);
+        }
+        TokenType::IntegerValue => {
+            node.node_type = ASTNodeType::IntegerLiteral;
+            node.token = structs::create_raw_pointer(token);
+        }
+        TokenType::DecimalValue => {
+            node.node_type = ASTNodeType::FloatLiteral;
+            node.token = structs::create_raw_pointer(token);
+        }
+        TokenType::Identifier => {
+            node.node_type = ASTNodeType::Identifier;
+            node.token = structs::create_raw_pointer(token);
+            ast_data.token_index += 1;
+            return parse_postfix(ast_data, node);
+        }
+        TokenType::StringValue => {
+            node.node_type = ASTNodeType::StringLiteral;
+            node.token = structs::create_raw_pointer(token);
+        }
+        TokenType::CharValue => {
+            node.node_type = ASTNodeType::CharLiteral;
+            node.token = structs::create_raw_pointer(token);
+        }
+        TokenType::LeftParenthesis => return parse_primary_left_parenthesis(ast_data, token),
+        TokenType::RightParenthesis => return node,
+        //TokenType.And => return ProcessReference(ast_data, token, allocator),
+        TokenType::Minus => return parse_minus(ast_data, token),
+        _ => {
+            ast_data.error_detail = format!("Unexpected type in expression, {}", enums::token_type_to_string(token.token_type));
+            ast_data.error_token = token;
+            ast_data.error_code = AstError::UnexpectedType;
+            return node;
+        }
+    }
+
+    ast_data.token_index += 1;
+    return node;
+}
+
+pub fn parse_binary_expression_any(ast_data:&mut ASTData, min_prec:usize, node_type:i32) -> ASTNode {
+
+    ast_data.error_function = "parseBinaryExprAny".to_string();
+    let mut left: ASTNode = parse_primary_any(ast_data);
+
+    let mut white_count: usize = 0;
+    let max_loops: usize = 1000;
+
+    while ast_data.token_index_in_bounds() {
+        
+        if debugging::is_infinite_while(&mut white_count, max_loops) {
+            return left;
+        }
+
+        let operator_token: Token = ast_data.get_token();
+
+        if token_utils::is_binary_operator_bool(operator_token.token_type) == false {
+            break;
+        }
+
+        let precedence: usize = token_utils::get_precedence_bool(operator_token.token_type);
+
+        if precedence < min_prec {
+            break;
+        }
+
+        ast_data.token_index += 1; // move past operator
+        let right: ASTNode = parse_binary_expression_any(ast_data, precedence + 1, node_type);
+        if ast_data.is_error() {
+            return left;
+        }
+
+        if right.node_type == ASTNodeType::Invalid {
+            ast_data.error_detail = "Missing value after equation symbol".to_string();
+            ast_data.error_token = operator_token;
+            ast_data.error_code = AstError::UnexpectedType;
+            return left;
+        }
+
+        let mut new_node: ASTNode = structs::get_default_node();
+
+        new_node.node_type = node_type;
+        new_node.token = structs::create_raw_pointer(operator_token);
+        new_node.left = structs::create_raw_pointer(left);
+        new_node.right = structs::create_raw_pointer(right);
+
+        left = new_node;
+    }
+
+    //'?' has the lowest precedence so only a top level expression can take it
+    if min_prec == 0 && ast_data.token_index_in_bounds() {
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::QuestionMark {
+            return parse_ternary(ast_data, left, token, node_type);
+        }
+    }
+    return left;
+}
+
+fn parse_ternary(ast_data: &mut ASTData, condition: ASTNode, question_token: Token, node_type: i32) -> ASTNode {
+    ast_data.error_function = "parseTernary".to_string();
+
+    let mut ternary_node: ASTNode = structs::get_default_node();
+
+    //skip '?'
+    if ast_data.increment_index() == false {
+        return ternary_node;
+    }
+    let true_node: ASTNode = parse_binary_expression_any(ast_data, 0, node_type);
+    if ast_data.is_error() {
+        return ternary_node;
+    }
+
+    if ast_data.expect_type(TokenType::Colon, "Missing expected ':' in ternary") == false {
+        ast_data.error_token = question_token;
+        return ternary_node;
+    }
+    if ast_data.increment_index() == false {
+        return ternary_node;
+    }
+    let false_node: ASTNode = parse_binary_expression_any(ast_data, 0, node_type);
+    if ast_data.is_error() {
+        return ternary_node;
+    }
+
+    if true_node.node_type == ASTNodeType::Invalid || false_node.node_type == ASTNodeType::Invalid {
+        ast_data.error_detail = "Missing value in ternary".to_string();
+        ast_data.error_token = question_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return ternary_node;
+    }
+
+    //Node_type: Ternary
+    //Token:     '?'
+    //Left:      condition
+    //Middle:    value if true
+    //Right:     value if false
+    ternary_node.node_type = ASTNodeType::Ternary;
+    ternary_node.token = structs::create_raw_pointer(question_token);
+    ternary_node.left = structs::create_raw_pointer(condition);
+    ternary_node.middle = structs::create_raw_pointer(true_node);
+    ternary_node.right = structs::create_raw_pointer(false_node);
+    return ternary_node;
+}
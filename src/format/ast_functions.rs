@@ -1,146 +1,330 @@
-use crate::core::enums;
-use crate::core::errors;
-use crate::core::structs;
-use crate::format::ast_expressions;
-use crate::format::ast_print;
-use enums::ASTNodeType;
-use enums::TokenType;
-use errors::AstError;
-use structs::ASTData;
-use structs::ASTNode;
-use structs::Token;
-
-pub fn process_function_declaration(ast_data: &mut ASTData) {
-    ast_data.error_function = "processFunctionDeclaration".to_string();
-
-    if ast_data.increment_index() == false {
-        return;
-    }
-
-    let type_node: ASTNode = ast_expressions::create_complex_declarations(ast_data);
-    if ast_data.is_error() {
-        return;
-    }
-
-    //var name expected
-    let var_name_token: Token = ast_data.get_token();
-    if ast_data.is_error() {
-        return;
-    }
-
-    if var_name_token.token_type != TokenType::Identifier {
-        ast_data.error_detail = "Missing expected function name".to_string();
-        ast_data.error_code = AstError::MissingExpectedType;
-    }
-    if ast_data.increment_index() == false {
-        return;
-    }
-    if ast_data.expect_type(TokenType::LeftParenthesis, "Missing expected '('") == false {
-        return;
-    }
-    if ast_data.increment_index() == false {
-        return;
-    }
-
-    //get parameters
-    let parameters_node: ASTNode = ast_expressions::fill_parameters(ast_data);
-    if ast_data.is_error() {
-        return;
-    }
-
-    //expect ')'
-    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')'") == false {
-        return;
-    }
-    if ast_data.increment_index() == false {
-        return;
-    }
-    if ast_data.expect_type(TokenType::LeftBrace, "Missing expected '{'") == false {
-        return;
-    }
-    ast_data.token_index += 1;
-
-    let function_body_node: ASTNode = build_body_block(ast_data, ASTNodeType::FunctionBody);
-    if ast_data.is_error() {
-        return;
-    }
-
-    if ast_data.expect_type(TokenType::RightBrace, "Missing expected '}'") == false {
-        return;
-    }
-    ast_data.token_index += 1;
-
-    let box_var_name_token: Box<Token> = Box::new(var_name_token);
-    let box_function_body: Box<ASTNode> = Box::new(function_body_node);
-    let box_type: Box<ASTNode> = Box::new(type_node);
-    let box_parameters: Box<ASTNode> = Box::new(parameters_node);
-
-    let mut function_node: ASTNode = structs::get_default_node();
-    function_node.node_type = ASTNodeType::FunctionDeclaration;
-    function_node.token = Box::into_raw(box_var_name_token);
-    function_node.left = Box::into_raw(box_type);
-    function_node.middle = Box::into_raw(box_parameters);
-    function_node.right = Box::into_raw(box_function_body);
-
-    ast_data.ast_nodes.push(function_node);
-}
-
-pub fn build_body_block(ast_data: &mut ASTData, node_type: i32) -> ASTNode {
-    ast_data.error_function = "buildBodyBlock".to_string();
-
-    let token_count: usize = ast_data.token_list.len();
-
-    let mut block_node: ASTNode = structs::get_default_node();
-
-    let child_list: Vec<ASTNode> = Vec::new();
-
-    block_node.children = child_list;
-    block_node.node_type = node_type;
-
-    while ast_data.token_index < token_count {
-        let index_before: usize = ast_data.token_index;
-
-        let token: Token = ast_data.get_token();
-        if token.token_type == TokenType::RightBrace {
-            break;
-        }
-        process_function_token_ast(
-            ast_data,
-            token,
-            &mut block_node,
-            false, //is const
-        );
-
-        if ast_data.is_error() {
-            return block_node;
-        }
-
-        if index_before == ast_data.token_index {
-            ast_data.token_index += 1;
-        }
-    }
-
-    return block_node;
-}
-
-pub fn process_function_token_ast(ast_data:&mut ASTData, first_token:Token, block_node:&mut ASTNode, is_const:bool) {
-
-    ast_data.error_function = "processFunctionTokenAST".to_string();
-
-    match first_token.token_type {
-
-        TokenType::Println => {
-            let print_node:ASTNode = ast_print::process_print(ast_data, true);
-            if ast_data.is_error() {
-                return;
-            }
-            block_node.children.push(print_node);
-        },
-        _ => {            
-            ast_data.error_detail = "unimplemented type in function".to_string();
-            ast_data.error_token = first_token;
-            ast_data.error_code = AstError::UnimplementedType;
-            return;
-        },
-    }
-}
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::format::ast_control_flow;
+use crate::format::ast_declarations;
+use crate::format::ast_expressions;
+use crate::format::ast_print;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::Token;
+
+pub fn process_function_declaration(ast_data: &mut ASTData) {
+    ast_data.error_function = "processFunctionDeclaration".to_string();
+
+    if ast_data.increment_index() == false {
+        return;
+    }
+
+    //fn <T> T max(T a, T b)
+    let mut type_parameters: Vec<ASTNode> = Vec::new();
+    let generic_token: Token = ast_data.get_token();
+    if generic_token.token_type == TokenType::LessThan {
+        type_parameters = process_type_parameters(ast_data);
+        if ast_data.is_error() {
+            return;
+        }
+        ast_data.error_function = "processFunctionDeclaration".to_string();
+    }
+
+    let type_node: ASTNode = ast_expressions::create_complex_declarations(ast_data);
+    if ast_data.is_error() {
+        return;
+    }
+
+    //var name expected
+    let var_name_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return;
+    }
+
+    if var_name_token.token_type != TokenType::Identifier {
+        ast_data.error_detail = "Missing expected function name".to_string();
+        ast_data.error_code = AstError::MissingExpectedType;
+    }
+    if ast_data.increment_index() == false {
+        return;
+    }
+    if ast_data.expect_type(TokenType::LeftParenthesis, "Missing expected '('") == false {
+        return;
+    }
+    if ast_data.increment_index() == false {
+        return;
+    }
+
+    //get parameters
+    let parameters_node: ASTNode = ast_expressions::fill_parameters(ast_data);
+    if ast_data.is_error() {
+        return;
+    }
+
+    //expect ')'
+    if ast_data.expect_type(TokenType::RightParenthesis, "Missing expected ')'") == false {
+        return;
+    }
+    if ast_data.increment_index() == false {
+        return;
+    }
+    if ast_data.expect_type(TokenType::LeftBrace, "Missing expected '{'") == false {
+        return;
+    }
+    ast_data.token_index += 1;
+
+    let function_body_node: ASTNode = build_body_block(ast_data, ASTNodeType::FunctionBody);
+    if ast_data.is_error() {
+        return;
+    }
+
+    if ast_data.expect_type(TokenType::RightBrace, "Missing expected '}'") == false {
+        return;
+    }
+    ast_data.token_index += 1;
+
+    let box_var_name_token: Box<Token> = Box::new(var_name_token);
+    let box_function_body: Box<ASTNode> = Box::new(function_body_node);
+    let box_type: Box<ASTNode> = Box::new(type_node);
+    let box_parameters: Box<ASTNode> = Box::new(parameters_node);
+
+    let mut function_node: ASTNode = structs::get_default_node();
+    function_node.node_type = ASTNodeType::FunctionDeclaration;
+    function_node.token = Box::into_raw(box_var_name_token);
+    function_node.left = Box::into_raw(box_type);
+    function_node.middle = Box::into_raw(box_parameters);
+    function_node.right = Box::into_raw(box_function_body);
+    function_node.children = type_parameters; // TypeParameter nodes, empty unless generic
+
+    ast_data.ast_nodes.push(function_node);
+}
+
+fn process_type_parameters(ast_data: &mut ASTData) -> Vec<ASTNode> {
+    ast_data.error_function = "processTypeParameters".to_string();
+
+    let mut type_parameters: Vec<ASTNode> = Vec::new();
+
+    let open_token: Token = ast_data.get_token();
+    //skip '<'
+    if ast_data.increment_index() == false {
+        return type_parameters;
+    }
+
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return type_parameters;
+        }
+
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::GreaterThan {
+            break;
+        }
+        if token.token_type == TokenType::Comma {
+            ast_data.token_index += 1;
+            continue;
+        }
+        if token.token_type != TokenType::Identifier {
+            ast_data.error_detail = "Expected a type parameter name".to_string();
+            ast_data.error_token = token;
+            ast_data.error_code = AstError::UnexpectedType;
+            return type_parameters;
+        }
+
+        let mut type_parameter: ASTNode = structs::get_default_node();
+        type_parameter.node_type = ASTNodeType::TypeParameter;
+        type_parameter.token = structs::create_raw_pointer(token);
+        type_parameters.push(type_parameter);
+        ast_data.token_index += 1;
+    }
+
+    if ast_data.expect_type(TokenType::GreaterThan, "Missing expected '>' after type parameters") == false {
+        ast_data.error_token = open_token;
+        return type_parameters;
+    }
+    if type_parameters.len() == 0 {
+        ast_data.error_detail = "Empty type parameter list".to_string();
+        ast_data.error_token = open_token;
+        ast_data.error_code = AstError::UnexpectedType;
+        return type_parameters;
+    }
+    ast_data.increment_index();
+
+    return type_parameters;
+}
+
+pub fn build_body_block(ast_data: &mut ASTData, node_type: i32) -> ASTNode {
+    ast_data.error_function = "buildBodyBlock".to_string();
+
+    let token_count: usize = ast_data.token_list.len();
+
+    let mut block_node: ASTNode = structs::get_default_node();
+
+    let child_list: Vec<ASTNode> = Vec::new();
+
+    block_node.children = child_list;
+    block_node.node_type = node_type;
+
+    while ast_data.token_index < token_count {
+        let index_before: usize = ast_data.token_index;
+
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::RightBrace {
+            break;
+        }
+        process_function_token_ast(ast_data, token, &mut block_node);
+
+        if ast_data.is_error() {
+            return block_node;
+        }
+
+        if index_before == ast_data.token_index {
+            ast_data.token_index += 1;
+        }
+    }
+
+    return block_node;
+}
+
+pub fn process_function_token_ast(ast_data:&mut ASTData, first_token:Token, block_node:&mut ASTNode) {
+
+    ast_data.error_function = "processFunctionTokenAST".to_string();
+
+    let statement_node:ASTNode;
+
+    match first_token.token_type {
+
+        TokenType::Println => {
+            statement_node = ast_print::process_print(ast_data, true);
+        },
+        TokenType::Print => {
+            statement_node = ast_print::process_print(ast_data, false);
+        },
+        TokenType::Return => {
+            statement_node = process_return(ast_data);
+        },
+        TokenType::If => {
+            statement_node = ast_control_flow::process_if(ast_data);
+        },
+        TokenType::While => {
+            statement_node = ast_control_flow::process_while(ast_data);
+        },
+        TokenType::For => {
+            statement_node = ast_control_flow::process_for(ast_data);
+        },
+        TokenType::Do => {
+            statement_node = ast_control_flow::process_do_while(ast_data);
+        },
+        TokenType::Break | TokenType::Continue => {
+            statement_node = ast_control_flow::process_loop_exit(ast_data);
+        },
+        TokenType::Spawn => {
+            statement_node = process_spawn(ast_data);
+        },
+        TokenType::Const | TokenType::Fn | TokenType::Chan => {
+            statement_node = ast_declarations::process_declaration(ast_data);
+        },
+        TokenType::Identifier => {
+            statement_node = ast_declarations::process_identifier_statement(ast_data);
+        },
+        _ => {
+            if token_utils::is_var_type(first_token.token_type) && first_token.token_type != TokenType::Void {
+                statement_node = ast_declarations::process_declaration(ast_data);
+            } else {
+                ast_data.error_detail = "unimplemented type in function".to_string();
+                ast_data.error_token = first_token;
+                ast_data.error_code = AstError::UnimplementedType;
+                return;
+            }
+        },
+    }
+    if ast_data.is_error() {
+        return;
+    }
+    block_node.children.push(statement_node);
+}
+
+// spawn worker(i, results);
+fn process_spawn(ast_data:&mut ASTData) -> ASTNode {
+
+    ast_data.error_function = "processSpawn".to_string();
+
+    let mut spawn_node:ASTNode = structs::get_default_node();
+
+    let spawn_token:Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return spawn_node;
+    }
+
+    //skip spawn
+    if ast_data.increment_index() == false {
+        return spawn_node;
+    }
+
+    let call_node:ASTNode = ast_expressions::parse_primary_any(ast_data);
+    if ast_data.is_error() {
+        return spawn_node;
+    }
+    if call_node.node_type != ASTNodeType::FunctionCall {
+        ast_data.error_detail = "spawn needs a function call, e.g. spawn worker(i);".to_string();
+        ast_data.error_token = spawn_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return spawn_node;
+    }
+
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after spawn") == false {
+        ast_data.error_token = spawn_token;
+        return spawn_node;
+    }
+    ast_data.token_index += 1;
+
+    //Node_type: Spawn
+    //Token:     spawn
+    //Left:      FunctionCall run on its own goroutine
+    spawn_node.node_type = ASTNodeType::Spawn;
+    spawn_node.token = structs::create_raw_pointer(spawn_token);
+    spawn_node.left = structs::create_raw_pointer(call_node);
+
+    return spawn_node;
+}
+
+fn process_return(ast_data:&mut ASTData) -> ASTNode {
+
+    ast_data.error_function = "processReturn".to_string();
+
+    let mut return_node:ASTNode = structs::get_default_node();
+    return_node.node_type = ASTNodeType::Return;
+
+    let return_token:Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return return_node;
+    }
+    return_node.token = structs::create_raw_pointer(return_token.clone());
+
+    //skip return
+    if ast_data.increment_index() == false {
+        return return_node;
+    }
+
+    let token:Token = ast_data.get_token();
+    if token.token_type != TokenType::Semicolon {
+        //Left: returned value, null for 'return;'
+        let value_node:ASTNode = ast_expressions::parse_binary_expression_any(ast_data, 0, ASTNodeType::ReturnExpression);
+        if ast_data.is_error() {
+            return return_node;
+        }
+        return_node.left = structs::create_raw_pointer(value_node);
+    }
+
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after return") == false {
+        ast_data.error_token = return_token;
+        return return_node;
+    }
+    ast_data.token_index += 1;
+
+    return return_node;
+}
@@ -0,0 +1,55 @@
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::Token;
+
+/// import "path/to/file"; the file itself is loaded by ast_modules
+pub fn process_import(ast_data: &mut ASTData) {
+    ast_data.error_function = "processImport".to_string();
+
+    let import_token: Token = ast_data.get_token();
+
+    //skip import
+    if ast_data.increment_index() == false {
+        return;
+    }
+
+    let path_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return;
+    }
+    if path_token.token_type != TokenType::StringValue {
+        ast_data.error_detail = "Missing expected file path in quotes after import".to_string();
+        ast_data.error_token = import_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return;
+    }
+    if path_token.text.is_empty() {
+        ast_data.error_detail = "Import path is empty".to_string();
+        ast_data.error_token = path_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return;
+    }
+    ast_data.token_index += 1;
+
+    //optional ';' after the path
+    if ast_data.token_index_in_bounds() {
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::Semicolon {
+            ast_data.token_index += 1;
+        }
+    }
+
+    //Node_type: Import
+    //Token:     path, as written
+    let mut import_node: ASTNode = structs::get_default_node();
+    import_node.node_type = ASTNodeType::Import;
+    import_node.token = structs::create_raw_pointer(path_token);
+
+    ast_data.ast_nodes.push(import_node);
+}
@@ -0,0 +1,154 @@
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::format::ast;
+use crate::parse::parsing;
+use enums::ASTNodeType;
+use errors::AstError;
+use errors::ModuleError;
+use errors::ParseError;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use structs::ASTNode;
+use structs::ModuleData;
+use structs::SourceFile;
+use structs::Token;
+
+/// Loads the file at path and everything it imports, each file once
+pub fn load_program(module_data: &mut ModuleData, path: &str) {
+    load_module(module_data, path, structs::empty_token());
+}
+
+/// Same as load_program for code that isn't on disk, its imports are found from the working directory
+pub fn load_program_from_code(module_data: &mut ModuleData, name: &str, code: &String) {
+    load_source(module_data, name, String::new(), code.clone());
+}
+
+fn load_module(module_data: &mut ModuleData, path: &str, import_token: Token) {
+
+    let canonical_path: String = match fs::canonicalize(path) {
+        Ok(full_path) => full_path.to_string_lossy().to_string(),
+        Err(_) => {
+            module_data.error_token = import_token;
+            module_data.error_detail = format!("cannot find '{}'", path);
+            module_data.error_code = ModuleError::FileNotFound;
+            return;
+        }
+    };
+
+    //an import of a file still loading its own imports leads back round to itself
+    let stack_count: usize = module_data.import_stack.len();
+    for i in 0..stack_count {
+        let file_index: usize = module_data.import_stack[i];
+        if module_data.canonical_paths[file_index] != canonical_path {
+            continue;
+        }
+        let mut cycle_text: String = String::new();
+        for cycle_index in i..stack_count {
+            let cycle_file_index: usize = module_data.import_stack[cycle_index];
+            cycle_text.push_str(&module_data.source_files[cycle_file_index].path);
+            cycle_text.push_str(" -> ");
+        }
+        cycle_text.push_str(&module_data.source_files[file_index].path);
+
+        module_data.error_token = import_token;
+        module_data.error_detail = format!("import cycle: {}", cycle_text);
+        module_data.error_code = ModuleError::ImportCycle;
+        return;
+    }
+
+    //already loaded through another import
+    let file_count: usize = module_data.canonical_paths.len();
+    for i in 0..file_count {
+        if module_data.canonical_paths[i] == canonical_path {
+            return;
+        }
+    }
+
+    let code: String = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(_) => {
+            module_data.error_token = import_token;
+            module_data.error_detail = format!("cannot read '{}'", path);
+            module_data.error_code = ModuleError::FileNotFound;
+            return;
+        }
+    };
+
+    load_source(module_data, path, canonical_path, code);
+}
+
+fn load_source(module_data: &mut ModuleData, path: &str, canonical_path: String, code: String) {
+
+    let file_index: usize = module_data.source_files.len();
+    module_data.source_files.push(SourceFile {
+        path: path.to_string(),
+        code,
+    });
+    module_data.canonical_paths.push(canonical_path);
+
+    let mut parse_error: i32 = ParseError::None;
+    let tokens: Vec<Token> = parsing::parse_to_tokens(&module_data.source_files[file_index].code, file_index, &mut parse_error);
+    if parse_error != ParseError::None {
+        module_data.error_detail = format!("{} in '{}'", errors::parse_error_to_string(parse_error), path);
+        module_data.error_code = ModuleError::ParseFailed;
+        return;
+    }
+
+    let mut ast_error: i32 = AstError::None;
+    let ast_nodes: Vec<ASTNode> = ast::build_asts(&tokens, &module_data.source_files[file_index], &mut ast_error);
+    module_data.tokens.extend(tokens);
+    if ast_error != AstError::None {
+        module_data.error_detail = format!("{} in '{}'", errors::ast_error_to_string(ast_error), path);
+        module_data.error_code = ModuleError::FormatFailed;
+        return;
+    }
+
+    //imported files go first so the output reads top down
+    module_data.import_stack.push(file_index);
+
+    let node_count: usize = ast_nodes.len();
+    for i in 0..node_count {
+        let node: &ASTNode = &ast_nodes[i];
+        if node.node_type != ASTNodeType::Import {
+            continue;
+        }
+        unsafe {
+            let path_token: Token = (*node.token).clone();
+            let import_path: String = resolve_import_path(path, &path_token.text);
+            load_module(module_data, &import_path, path_token);
+        }
+        if module_data.is_error() {
+            return;
+        }
+    }
+
+    module_data.import_stack.pop();
+
+    for i in 0..node_count {
+        if ast_nodes[i].node_type == ASTNodeType::Import {
+            continue;
+        }
+        module_data.ast_nodes.push(ast_nodes[i].clone());
+    }
+}
+
+/// Import paths are relative to the importing file, the extension can be left off when it's the same
+fn resolve_import_path(importer_path: &str, import_text: &str) -> String {
+    let importer: &Path = Path::new(importer_path);
+
+    let mut import_path: PathBuf = PathBuf::from(import_text);
+    if import_path.is_relative() {
+        if let Some(directory) = importer.parent() {
+            import_path = directory.join(import_text);
+        }
+    }
+
+    if import_path.exists() == false && import_path.extension().is_none() {
+        if let Some(extension) = importer.extension() {
+            import_path.set_extension(extension);
+        }
+    }
+    return import_path.to_string_lossy().to_string();
+}
@@ -0,0 +1,123 @@
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::format::ast_expressions;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::AstError;
+use structs::ASTData;
+use structs::ASTNode;
+use structs::Token;
+
+pub fn process_struct_declaration(ast_data: &mut ASTData) {
+    ast_data.error_function = "processStructDeclaration".to_string();
+
+    //skip struct
+    if ast_data.increment_index() == false {
+        return;
+    }
+
+    let name_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return;
+    }
+    if name_token.token_type != TokenType::Identifier {
+        ast_data.error_detail = "Missing expected struct name".to_string();
+        ast_data.error_token = name_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return;
+    }
+    if ast_data.increment_index() == false {
+        return;
+    }
+    if ast_data.expect_type(TokenType::LeftBrace, "Missing expected '{' after struct name") == false {
+        ast_data.error_token = name_token;
+        return;
+    }
+    if ast_data.increment_index() == false {
+        return;
+    }
+
+    //Node_type: StructDeclaration
+    //Token:     struct name
+    //Children:  StructField nodes
+    let mut struct_node: ASTNode = structs::get_default_node();
+    struct_node.node_type = ASTNodeType::StructDeclaration;
+    struct_node.token = structs::create_raw_pointer(name_token.clone());
+
+    let mut while_count: usize = 0;
+
+    while ast_data.token_index_in_bounds() {
+        if debugging::is_infinite_while(&mut while_count, 1000) {
+            ast_data.error_code = AstError::InfiniteWhileLoop;
+            return;
+        }
+
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::RightBrace {
+            break;
+        }
+
+        let field_node: ASTNode = process_struct_field(ast_data);
+        if ast_data.is_error() {
+            return;
+        }
+        struct_node.children.push(field_node);
+    }
+
+    if ast_data.expect_type(TokenType::RightBrace, "Missing expected '}' after struct fields") == false {
+        ast_data.error_token = name_token;
+        return;
+    }
+    ast_data.token_index += 1;
+
+    //optional ';' after the closing brace
+    if ast_data.token_index_in_bounds() {
+        let token: Token = ast_data.get_token();
+        if token.token_type == TokenType::Semicolon {
+            ast_data.token_index += 1;
+        }
+    }
+
+    ast_data.ast_nodes.push(struct_node);
+}
+
+fn process_struct_field(ast_data: &mut ASTData) -> ASTNode {
+    ast_data.error_function = "processStructField".to_string();
+
+    let mut field_node: ASTNode = structs::get_default_node();
+
+    let type_node: ASTNode = ast_expressions::create_complex_declarations(ast_data);
+    if ast_data.is_error() {
+        return field_node;
+    }
+
+    let name_token: Token = ast_data.get_token();
+    if ast_data.is_error() {
+        return field_node;
+    }
+    if name_token.token_type != TokenType::Identifier {
+        ast_data.error_detail = "Missing expected field name".to_string();
+        ast_data.error_token = name_token;
+        ast_data.error_code = AstError::MissingExpectedType;
+        return field_node;
+    }
+    if ast_data.increment_index() == false {
+        return field_node;
+    }
+    if ast_data.expect_type(TokenType::Semicolon, "Missing expected ';' after struct field") == false {
+        ast_data.error_token = name_token;
+        return field_node;
+    }
+    ast_data.token_index += 1;
+
+    //Node_type: StructField
+    //Token:     field name
+    //Left:      type node
+    field_node.node_type = ASTNodeType::StructField;
+    field_node.token = structs::create_raw_pointer(name_token);
+    field_node.left = structs::create_raw_pointer(type_node);
+
+    return field_node;
+}
@@ -1,7 +1,12 @@
-
-
-pub mod ast;
-pub mod ast_functions;
-pub mod ast_expressions;
-pub mod ast_print;
-pub mod ast_utils;
\ No newline at end of file
+
+
+pub mod ast;
+pub mod ast_functions;
+pub mod ast_expressions;
+pub mod ast_print;
+pub mod ast_utils;
+pub mod ast_declarations;
+pub mod ast_structs;
+pub mod ast_imports;
+pub mod ast_modules;
+pub mod ast_control_flow;
@@ -0,0 +1,277 @@
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::printing;
+use crate::core::structs;
+use crate::core::type_utils;
+use crate::lint::lint_rules;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use structs::ASTNode;
+use structs::CompileOptions;
+use structs::LintData;
+use structs::SourceFile;
+use structs::SymbolTable;
+use structs::Token;
+
+/// Checks a program that has passed semantic analysis against the style rules turned on in options.
+/// Everything found is a warning, the program is still converted
+pub fn lint(ast_nodes:&Vec<ASTNode>, tokens:&Vec<Token>, source_files:&Vec<SourceFile>, options:&CompileOptions) {
+    if options.lint_rules.len() == 0 {
+        return;
+    }
+    print!("\t{}Linting{}\t\t\t", printing::GREY, printing::RESET);
+
+    let mut lint_data = LintData {
+        tokens,
+        options,
+        symbol_table: SymbolTable::new(),
+        source_paths: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let source_count: usize = source_files.len();
+    for i in 0..source_count {
+        lint_data.source_paths.push(source_files[i].path.clone());
+    }
+
+    //a local named like a function, struct or global hides it too
+    let node_count: usize = ast_nodes.len();
+    for i in 0..node_count {
+        let node: &ASTNode = &ast_nodes[i];
+        if node.token.is_null() {
+            continue;
+        }
+        unsafe {
+            let name_token: Token = (*node.token).clone();
+            if node.node_type == ASTNodeType::FunctionDeclaration {
+                lint_data.symbol_table.add_symbol(&name_token.text, "", SymbolKind::Function, name_token.clone(), true);
+            } else if node.node_type == ASTNodeType::StructDeclaration {
+                lint_data.symbol_table.add_symbol(&name_token.text, "", SymbolKind::Struct, name_token.clone(), true);
+            } else if node.node_type == ASTNodeType::Declaration {
+                lint_declaration(&mut lint_data, node);
+            }
+        }
+    }
+
+    for i in 0..node_count {
+        let node: &ASTNode = &ast_nodes[i];
+        if node.node_type != ASTNodeType::FunctionDeclaration {
+            continue;
+        }
+        lint_function(&mut lint_data, node);
+    }
+
+    print!("{}Done{}\n", printing::CYAN, printing::RESET);
+    debugging::print_warnings(&lint_data.warnings, source_files);
+}
+
+fn lint_function(lint_data:&mut LintData, node:&ASTNode) {
+
+    //Node_type: FunctionDeclaration
+    //Token:     function name
+    //Middle:    Parameters
+    //Right:     FunctionBody
+
+    if node.token.is_null() {
+        return;
+    }
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        lint_rules::check_function_name(lint_data, &name_token);
+        lint_rules::check_function_length(lint_data, &name_token);
+
+        lint_data.symbol_table.enter_scope();
+        if node.middle.is_null() == false {
+            let parameters:&ASTNode = &*node.middle;
+            let parameter_count:usize = parameters.children.len();
+            for i in 0..parameter_count {
+                let parameter:&ASTNode = &parameters.children[i];
+                if parameter.token.is_null() {
+                    continue;
+                }
+                let parameter_token:Token = (*parameter.token).clone();
+                declare_name(lint_data, &parameter_token, SymbolKind::Parameter);
+            }
+        }
+        if node.right.is_null() == false {
+            lint_body(lint_data, &*node.right, 0);
+        }
+        lint_data.symbol_table.exit_scope();
+    }
+}
+
+// Shadowing is checked before the name is added, so it only finds the names from outer scopes
+fn declare_name(lint_data:&mut LintData, name_token:&Token, symbol_kind:i32) {
+    lint_rules::check_shadowing(lint_data, name_token);
+    lint_data.symbol_table.add_symbol(&name_token.text, "", symbol_kind, name_token.clone(), false);
+}
+
+// depth is how many blocks the statements sit inside, 0 for the function body's own
+fn lint_body(lint_data:&mut LintData, node:&ASTNode, depth:usize) {
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        lint_statement(lint_data, &node.children[i], depth);
+    }
+}
+
+fn lint_block(lint_data:&mut LintData, body_node_ptr:*mut ASTNode, depth:usize) {
+    if body_node_ptr.is_null() {
+        return;
+    }
+    lint_data.symbol_table.enter_scope();
+    unsafe {
+        lint_body(lint_data, &*body_node_ptr, depth);
+    }
+    lint_data.symbol_table.exit_scope();
+}
+
+fn lint_statement(lint_data:&mut LintData, node:&ASTNode, depth:usize) {
+    if node.token.is_null() {
+        return;
+    }
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Print | ASTNodeType::Println => {
+                let child_count:usize = node.children.len();
+                for i in 0..child_count {
+                    lint_expression(lint_data, &node.children[i], false);
+                }
+            }
+            ASTNodeType::Declaration => lint_declaration(lint_data, node),
+            ASTNodeType::MultipleDeclaration => {
+                lint_optional_expression(lint_data, node.right, false);
+                let declaration_count:usize = node.children.len();
+                for i in 0..declaration_count {
+                    let declaration:&ASTNode = &node.children[i];
+                    if declaration.token.is_null() {
+                        continue;
+                    }
+                    let name_token:Token = (*declaration.token).clone();
+                    declare_name(lint_data, &name_token, SymbolKind::Variable);
+                }
+            }
+            ASTNodeType::Assignment => {
+                lint_optional_expression(lint_data, node.left, false);
+                lint_optional_expression(lint_data, node.right, false);
+            }
+            ASTNodeType::Return | ASTNodeType::Spawn => lint_optional_expression(lint_data, node.left, false),
+            ASTNodeType::FunctionCall => lint_expression(lint_data, node, false),
+            ASTNodeType::IfStatement => lint_if(lint_data, node, depth),
+            ASTNodeType::WhileLoop | ASTNodeType::DoWhileLoop => {
+                lint_rules::check_nesting_depth(lint_data, node, depth);
+                lint_optional_expression(lint_data, node.left, false);
+                lint_block(lint_data, node.right, depth + 1);
+            }
+            ASTNodeType::ForLoop => {
+                //Left:      ForCondition, its init, condition and step
+                //Right:     ForBody
+                lint_rules::check_nesting_depth(lint_data, node, depth);
+                lint_data.symbol_table.enter_scope();
+                if node.left.is_null() == false {
+                    let condition_node:&ASTNode = &*node.left;
+                    if condition_node.left.is_null() == false {
+                        lint_statement(lint_data, &*condition_node.left, depth);
+                    }
+                    lint_optional_expression(lint_data, condition_node.middle, false);
+                    if condition_node.right.is_null() == false {
+                        lint_statement(lint_data, &*condition_node.right, depth);
+                    }
+                }
+                lint_block(lint_data, node.right, depth + 1);
+                lint_data.symbol_table.exit_scope();
+            }
+            ASTNodeType::Label if node.left.is_null() == false => lint_statement(lint_data, &*node.left, depth),
+            _ => {}
+        }
+    }
+}
+
+fn lint_declaration(lint_data:&mut LintData, node:&ASTNode) {
+
+    //Node_type: Declaration
+    //Token:     variable name
+    //Right:     value, null if declared without one
+
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        //a const holding a number known up front is how a magic number gets its name
+        let is_constant:bool = node.is_const && node.right.is_null() == false && lint_rules::is_constant_value(&*node.right);
+        if is_constant {
+            lint_rules::check_constant_name(lint_data, &name_token);
+        }
+        lint_optional_expression(lint_data, node.right, is_constant);
+        declare_name(lint_data, &name_token, SymbolKind::Variable);
+    }
+}
+
+fn lint_if(lint_data:&mut LintData, node:&ASTNode, depth:usize) {
+
+    //Node_type: IfStatement
+    //Left:      condition
+    //Middle:    IfBody
+    //Right:     Else, its left an IfStatement for else if, otherwise ElseBody
+
+    lint_rules::check_nesting_depth(lint_data, node, depth);
+    lint_optional_expression(lint_data, node.left, false);
+    lint_block(lint_data, node.middle, depth + 1);
+    if node.right.is_null() {
+        return;
+    }
+    unsafe {
+        let else_node:&ASTNode = &*node.right;
+        if else_node.left.is_null() {
+            return;
+        }
+        //else if is a chain at the same depth, not an if inside the else
+        if (*else_node.left).node_type == ASTNodeType::IfStatement {
+            lint_if(lint_data, &*else_node.left, depth);
+            return;
+        }
+        lint_block(lint_data, else_node.left, depth + 1);
+    }
+}
+
+fn lint_optional_expression(lint_data:&mut LintData, node_ptr:*mut ASTNode, is_constant:bool) {
+    if node_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        lint_expression(lint_data, &*node_ptr, is_constant);
+    }
+}
+
+// is_constant for the value of a named constant, where numbers are allowed
+fn lint_expression(lint_data:&mut LintData, node:&ASTNode, is_constant:bool) {
+    if node.token.is_null() {
+        return;
+    }
+    unsafe {
+        match node.node_type {
+            ASTNodeType::IntegerLiteral | ASTNodeType::FloatLiteral => {
+                if is_constant == false {
+                    lint_rules::check_magic_number(lint_data, node);
+                }
+            }
+            ASTNodeType::FunctionCall => {
+                lint_rules::check_nested_calls(lint_data, node);
+                let argument_count:usize = node.children.len();
+                for i in 0..argument_count {
+                    lint_expression(lint_data, &node.children[i], is_constant);
+                }
+            }
+            ASTNodeType::FieldAccess | ASTNodeType::Minus => lint_optional_expression(lint_data, node.left, is_constant),
+            ASTNodeType::Cast => lint_optional_expression(lint_data, node.right, is_constant),
+            ASTNodeType::ArrayAccess | ASTNodeType::Ternary => {
+                lint_optional_expression(lint_data, node.left, is_constant);
+                lint_optional_expression(lint_data, node.middle, is_constant);
+                lint_optional_expression(lint_data, node.right, is_constant);
+            }
+            _ => {
+                if type_utils::is_binary_node(node) {
+                    lint_expression(lint_data, &*node.left, is_constant);
+                    lint_expression(lint_data, &*node.right, is_constant);
+                }
+            }
+        }
+    }
+}
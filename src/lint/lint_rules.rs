@@ -0,0 +1,238 @@
+use crate::core::enums;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::LintRule;
+use enums::SymbolKind;
+use enums::TokenType;
+use structs::ASTNode;
+use structs::LintData;
+use structs::Symbol;
+use structs::Token;
+
+// The README's rules, applied to the programs this compiles rather than to its own source.
+// Each check reports through lint_data.add_warning, which drops the rules that are turned off
+
+/// Counting starts at 0 for the function body, so the default of 4 allows if inside 4 loops
+pub fn check_nesting_depth(lint_data:&mut LintData, node:&ASTNode, depth:usize) {
+    //only the statement that first goes too deep is reported, not everything inside it
+    if depth != lint_data.options.max_nesting_depth {
+        return;
+    }
+    unsafe {
+        let keyword_token:Token = (*node.token).clone();
+        let max_nesting_depth:usize = lint_data.options.max_nesting_depth;
+        lint_data.add_warning(LintRule::NestingDepth, &keyword_token, format!(
+            "'{}' opens a block {} deep, the most allowed is {}",
+            keyword_token.text, depth + 1, max_nesting_depth
+        ));
+    }
+}
+
+/// From the line with the function's name to the line with its closing brace
+pub fn check_function_length(lint_data:&mut LintData, name_token:&Token) {
+    let tokens:&Vec<Token> = lint_data.tokens;
+    let token_count:usize = tokens.len();
+
+    let mut index:usize = 0;
+    while index < token_count {
+        let token:&Token = &tokens[index];
+        if token.file_index == name_token.file_index && token.line_number == name_token.line_number && token.char_number == name_token.char_number {
+            break;
+        }
+        index += 1;
+    }
+    while index < token_count && tokens[index].token_type != TokenType::LeftBrace {
+        index += 1;
+    }
+
+    let mut brace_depth:usize = 0;
+    while index < token_count {
+        let token_type:i32 = tokens[index].token_type;
+        if token_type == TokenType::LeftBrace {
+            brace_depth += 1;
+        } else if token_type == TokenType::RightBrace {
+            brace_depth -= 1;
+            if brace_depth == 0 {
+                break;
+            }
+        }
+        index += 1;
+    }
+    if index >= token_count {
+        return;
+    }
+
+    let line_count:usize = tokens[index].line_number - name_token.line_number + 1;
+    let max_function_lines:usize = lint_data.options.max_function_lines;
+    if line_count <= max_function_lines {
+        return;
+    }
+    lint_data.add_warning(LintRule::FunctionLength, name_token, format!(
+        "function '{}' is {} lines long, the most allowed is {}",
+        name_token.text, line_count, max_function_lines
+    ));
+}
+
+/// A name declared inside a function that hides a parameter, an outer local, a function or a struct
+pub fn check_shadowing(lint_data:&mut LintData, name_token:&Token) {
+    let existing:Symbol = lint_data.symbol_table.get_symbol(&name_token.text);
+    if existing.symbol_kind == SymbolKind::None {
+        return;
+    }
+    let kind_text:&str = match existing.symbol_kind {
+        SymbolKind::Parameter => "parameter",
+        SymbolKind::Function => "function",
+        SymbolKind::Struct => "struct",
+        _ => "variable",
+    };
+    let location:String = lint_data.get_source_location(&existing.token);
+    lint_data.add_warning(LintRule::Shadowing, name_token, format!(
+        "'{}' shadows the {} declared at {}",
+        name_token.text, kind_text, location
+    ));
+}
+
+/// Any number but 0 and 1 outside a constant's value should be a named constant
+pub fn check_magic_number(lint_data:&mut LintData, node:&ASTNode) {
+    unsafe {
+        let number_token:Token = (*node.token).clone();
+        let value:f64 = match number_token.text.parse::<f64>() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+        if value == 0.0 || value == 1.0 {
+            return;
+        }
+        lint_data.add_warning(LintRule::MagicNumbers, &number_token, format!(
+            "magic number {}, give it a name with a const declaration",
+            number_token.text
+        ));
+    }
+}
+
+/// Functions are snake_case like the Go they become, main included
+pub fn check_function_name(lint_data:&mut LintData, name_token:&Token) {
+    if is_snake_case(&name_token.text) {
+        return;
+    }
+    lint_data.add_warning(LintRule::FunctionNaming, name_token, format!(
+        "function '{}' should be snake_case, e.g. '{}'",
+        name_token.text, to_snake_case(&name_token.text)
+    ));
+}
+
+/// Constants are UPPER_SNAKE_CASE, a const holding a value worked out at run time is just a variable
+pub fn check_constant_name(lint_data:&mut LintData, name_token:&Token) {
+    if is_upper_snake_case(&name_token.text) {
+        return;
+    }
+    lint_data.add_warning(LintRule::ConstantNaming, name_token, format!(
+        "constant '{}' should be UPPER_SNAKE_CASE, e.g. '{}'",
+        name_token.text, to_snake_case(&name_token.text).to_uppercase()
+    ));
+}
+
+/// f(g(x)) is reported at g, its result should be given a name on the line before
+pub fn check_nested_calls(lint_data:&mut LintData, node:&ASTNode) {
+    let argument_count:usize = node.children.len();
+    for i in 0..argument_count {
+        let inner_call:*const ASTNode = find_function_call(&node.children[i]);
+        if inner_call.is_null() {
+            continue;
+        }
+        unsafe {
+            let inner_token:Token = (*(*inner_call).token).clone();
+            let outer_token:Token = (*node.token).clone();
+            lint_data.add_warning(LintRule::NestedCalls, &inner_token, format!(
+                "call to '{}' inside the arguments of '{}', give its result a name first",
+                inner_token.text, outer_token.text
+            ));
+        }
+    }
+}
+
+/// A literal, a negated one or arithmetic on them, the values that make a const a named constant
+pub fn is_constant_value(node:&ASTNode) -> bool {
+    unsafe {
+        match node.node_type {
+            ASTNodeType::IntegerLiteral | ASTNodeType::FloatLiteral | ASTNodeType::StringLiteral |
+            ASTNodeType::CharLiteral | ASTNodeType::BoolLiteral => return true,
+            ASTNodeType::Minus => return node.left.is_null() == false && is_constant_value(&*node.left),
+            _ => {}
+        }
+        if type_utils::is_binary_node(node) == false {
+            return false;
+        }
+        return is_constant_value(&*node.left) && is_constant_value(&*node.right);
+    }
+}
+
+// The first call anywhere in the expression, null if it has none
+fn find_function_call(node:&ASTNode) -> *const ASTNode {
+    if node.token.is_null() {
+        return std::ptr::null();
+    }
+    if node.node_type == ASTNodeType::FunctionCall {
+        return node as *const ASTNode;
+    }
+    let mut found:*const ASTNode = std::ptr::null();
+    let sides:[*mut ASTNode; 3] = [node.left, node.middle, node.right];
+    for i in 0..sides.len() {
+        if found.is_null() == false {
+            break;
+        }
+        if sides[i].is_null() {
+            continue;
+        }
+        //a cast's left is its type, which holds no calls
+        if node.node_type == ASTNodeType::Cast && i == 0 {
+            continue;
+        }
+        unsafe {
+            found = find_function_call(&*sides[i]);
+        }
+    }
+    return found;
+}
+
+fn is_snake_case(name:&str) -> bool {
+    let bytes:&[u8] = name.as_bytes();
+    for i in 0..bytes.len() {
+        let is_allowed:bool = bytes[i].is_ascii_lowercase() || bytes[i].is_ascii_digit() || bytes[i] == b'_';
+        if is_allowed == false {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn is_upper_snake_case(name:&str) -> bool {
+    let bytes:&[u8] = name.as_bytes();
+    for i in 0..bytes.len() {
+        let is_allowed:bool = bytes[i].is_ascii_uppercase() || bytes[i].is_ascii_digit() || bytes[i] == b'_';
+        if is_allowed == false {
+            return false;
+        }
+    }
+    return true;
+}
+
+// "maxSize" and "MaxSize" become "max_size", an uppercase run like "HTTPPort" stays one word, "http_port"
+fn to_snake_case(name:&str) -> String {
+    let characters:Vec<char> = name.chars().collect();
+    let character_count:usize = characters.len();
+    let mut snake_case:String = String::new();
+    for i in 0..character_count {
+        let character:char = characters[i];
+        if character.is_ascii_uppercase() && i > 0 && characters[i - 1] != '_' {
+            let previous_is_lower:bool = characters[i - 1].is_ascii_lowercase() || characters[i - 1].is_ascii_digit();
+            let next_is_lower:bool = i + 1 < character_count && characters[i + 1].is_ascii_lowercase();
+            if previous_is_lower || (characters[i - 1].is_ascii_uppercase() && next_is_lower) {
+                snake_case.push('_');
+            }
+        }
+        snake_case.push(character.to_ascii_lowercase());
+    }
+    return snake_case;
+}
@@ -0,0 +1,2 @@
+pub mod lint_analysis;
+pub mod lint_rules;
@@ -1,56 +1,182 @@
+// The README's style rules deliberately go against these lints
+#![allow(clippy::needless_return)]
+#![allow(clippy::bool_comparison)]
+#![allow(clippy::len_zero)]
+#![allow(clippy::needless_range_loop)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::print_with_newline)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::redundant_field_names)]
+#![allow(clippy::collapsible_if)]
+#![allow(clippy::collapsible_else_if)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+#![allow(clippy::manual_strip)]
+
 mod core;
 mod format;
 mod parse;
+mod semantic;
+mod lint;
 mod convert;
 
+#[cfg(test)]
+mod test_utils;
+
 use crate::core::errors::ConvertError;
-use crate::parse::parsing;
+use crate::core::errors::SemanticError;
 use crate::core::debugging;
+use crate::core::enums;
 use crate::core::errors;
 use crate::core::structs;
-use crate::core::structs::ASTNode;
-use crate::format::ast;
-use errors::AstError;
-use errors::ParseError;
-use structs::Token;
+use crate::format::ast_modules;
+use enums::LintRule;
+use enums::UninitializedMode;
+use enums::UnusedVariableMode;
+use errors::ModuleError;
+use structs::CompileOptions;
+use structs::ModuleData;
+use structs::SemanticResult;
 
-fn convert_code(code:&String) {
+fn convert_program(module_data:&mut ModuleData, options:&CompileOptions) {
 
-    println!("Input code:\n\t{}\n", code);
+    let file_count:usize = module_data.source_files.len();
+    for i in 0..file_count {
+        println!("Input code ({}):\n\t{}\n", module_data.source_files[i].path, module_data.source_files[i].code);
+    }
 
-    let mut parse_error:i32 = ParseError::None;
-    let tokens:Vec<Token> = parsing::parse_to_tokens(code, &mut parse_error);
-    if parse_error != ParseError::None {
-        println!("Error: {}", errors::parse_error_to_string(parse_error));
+    if module_data.is_error() {
+        println!("Error: {}", errors::module_error_to_string(module_data.error_code));
+        debugging::print_module_error(module_data);
+        if module_data.error_code == ModuleError::FormatFailed {
+            debugging::print_tokens(&module_data.tokens);
+        }
         return;
     }
 
-    let mut ast_error:i32 = AstError::None;
-    let ast_nodes:Vec<ASTNode> = ast::build_asts(&tokens, code, &mut ast_error);
-    if ast_error != AstError::None {
-        println!("Error: {}", errors::ast_error_to_string(ast_error));
-        debugging::print_tokens(&tokens);
+    let mut semantic_error:i32 = SemanticError::None;
+    let semantic_result:SemanticResult = semantic::semantic_analysis::analyse(
+        &module_data.ast_nodes, &module_data.source_files, options, &mut semantic_error
+    );
+    if semantic_error != SemanticError::None {
+        println!("Error: {}", errors::semantic_error_to_string(semantic_error));
+        debugging::print_tokens(&module_data.tokens);
+        debugging::print_ast_nodes(&module_data.ast_nodes);
         return;
     }
+    lint::lint_analysis::lint(&module_data.ast_nodes, &module_data.tokens, &module_data.source_files, options);
 
     let mut convert_error:i32 = ConvertError::None;
-    let code:String = convert::go_convert::convert(&ast_nodes, code, &mut convert_error);
+    let code:String = convert::go_convert::convert(&module_data.ast_nodes, &module_data.source_files, &semantic_result, &mut convert_error);
     if convert_error != ConvertError::None {
         println!("Error: {}", errors::convert_error_to_string(convert_error));
-        debugging::print_tokens(&tokens);
-        debugging::print_ast_nodes(&ast_nodes);
+        debugging::print_tokens(&module_data.tokens);
+        debugging::print_ast_nodes(&module_data.ast_nodes);
         return;
     }
 
     println!("\nGenerated Code:\n{}", code);
-    debugging::print_tokens(&tokens);
-    debugging::print_ast_nodes(&ast_nodes);
+    debugging::print_tokens(&module_data.tokens);
+    debugging::print_ast_nodes(&module_data.ast_nodes);
+}
+
+// Applies one --option, prints what is wrong with it and gives back false if it isn't one.
+// Lint options apply in order, "--lint --no-lint=magic_numbers" is every rule but one
+fn parse_option(arg:&String, options:&mut CompileOptions) -> bool {
+    if arg == "--unused=blank" {
+        options.unused_variable_mode = UnusedVariableMode::Blank;
+    } else if arg == "--unused=error" {
+        options.unused_variable_mode = UnusedVariableMode::Error;
+    } else if arg == "--uninitialized=error" {
+        options.uninitialized_mode = UninitializedMode::Error;
+    } else if arg == "--uninitialized=warning" {
+        options.uninitialized_mode = UninitializedMode::Warning;
+    } else if arg == "--lint" {
+        for lint_rule in 0..LintRule::Count {
+            options.enable_lint(lint_rule);
+        }
+    } else if arg.starts_with("--lint=") {
+        return parse_lint_rules(&arg["--lint=".len()..], options, true);
+    } else if arg.starts_with("--no-lint=") {
+        return parse_lint_rules(&arg["--no-lint=".len()..], options, false);
+    } else if arg.starts_with("--max-nesting=") {
+        return parse_lint_limit(arg, "--max-nesting=".len(), &mut options.max_nesting_depth);
+    } else if arg.starts_with("--max-function-lines=") {
+        return parse_lint_limit(arg, "--max-function-lines=".len(), &mut options.max_function_lines);
+    } else {
+        println!(
+            "Unknown option '{}', expected --unused=blank|error, --uninitialized=error|warning, --lint, --lint=<rules>, --no-lint=<rules>, --max-nesting=<n> or --max-function-lines=<n>",
+            arg
+        );
+        return false;
+    }
+    return true;
+}
+
+// "nesting_depth,shadowing", turned on or off
+fn parse_lint_rules(rule_names:&str, options:&mut CompileOptions, is_enabled:bool) -> bool {
+    let names:Vec<&str> = rule_names.split(',').collect();
+    for i in 0..names.len() {
+        let mut lint_rule:i32 = -1;
+        for rule in 0..LintRule::Count {
+            if enums::lint_rule_to_string(rule) == names[i] {
+                lint_rule = rule;
+            }
+        }
+        if lint_rule == -1 {
+            let mut rule_list:Vec<&str> = Vec::new();
+            for rule in 0..LintRule::Count {
+                rule_list.push(enums::lint_rule_to_string(rule));
+            }
+            println!("Unknown lint rule '{}', expected one of {}", names[i], rule_list.join(", "));
+            return false;
+        }
+        if is_enabled {
+            options.enable_lint(lint_rule);
+        } else {
+            options.disable_lint(lint_rule);
+        }
+    }
+    return true;
+}
+
+fn parse_lint_limit(arg:&String, prefix_length:usize, limit:&mut usize) -> bool {
+    match arg[prefix_length..].parse::<usize>() {
+        Ok(value) => *limit = value,
+        Err(_) => {
+            println!("Option '{}' needs a whole number", arg);
+            return false;
+        }
+    }
+    return true;
 }
 
 fn main() {
+    let mut module_data:ModuleData = ModuleData::new();
+
+    //compiler path/to/main.src [options], imports are loaded from there
+    let args:Vec<String> = std::env::args().collect();
+    let mut options:CompileOptions = CompileOptions::new();
+    let mut path:String = String::new();
+    for i in 1..args.len() {
+        if args[i].starts_with("--") == false {
+            path = args[i].clone();
+            continue;
+        }
+        if parse_option(&args[i], &mut options) == false {
+            return;
+        }
+    }
+    if path.is_empty() == false {
+        ast_modules::load_program(&mut module_data, &path);
+        convert_program(&mut module_data, &options);
+        return;
+    }
+
     let code:String = 
     "fn void main() {
         println(\"Hello world!\");    
     }".to_string();
-    convert_code(&code);
+    ast_modules::load_program_from_code(&mut module_data, "hello_world", &code);
+    convert_program(&mut module_data, &options);
 }
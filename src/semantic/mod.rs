@@ -0,0 +1,8 @@
+pub mod semantic_analysis;
+pub mod semantic_names;
+pub mod semantic_types;
+pub mod semantic_flow;
+pub mod semantic_const;
+pub mod semantic_init;
+pub mod semantic_constants;
+pub mod semantic_generics;
@@ -0,0 +1,371 @@
+use crate::core::debugging;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::printing;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::core::type_utils;
+use crate::semantic::semantic_flow;
+use crate::semantic::semantic_generics;
+use crate::semantic::semantic_init;
+use crate::semantic::semantic_names;
+use crate::semantic::semantic_types;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::CompileOptions;
+use structs::SemanticData;
+use structs::SemanticResult;
+use structs::SourceFile;
+use structs::Symbol;
+use structs::SymbolTable;
+use structs::Token;
+
+/// Checks the program's names before anything is written, every file's nodes at once
+/// so a name declared twice across imports is caught too.
+/// Gives back the locals that are never read, for convert to follow with _ = x,
+/// and the generic functions' constraints for convert to write
+pub fn analyse(ast_nodes:&Vec<ASTNode>, source_files:&Vec<SourceFile>, options:&CompileOptions, semantic_error:&mut i32) -> SemanticResult {
+    print!("\t{}Checking{}\t\t\t", printing::GREY, printing::RESET);
+
+    let mut semantic_data:SemanticData = create_semantic_data(ast_nodes, source_files, options);
+    check_program(&mut semantic_data);
+
+    if semantic_data.is_error() {
+        print!("{}Error{}\n", printing::RED, printing::RESET);
+        debugging::print_semantic_error(&semantic_data, source_files);
+        debugging::print_warnings(&semantic_data.warnings, source_files);
+        *semantic_error = semantic_data.error_code;
+        return SemanticResult {
+            unused_variables: Vec::new(),
+            generic_constraints: Vec::new(),
+        };
+    }
+    print!("{}Done{}\n", printing::CYAN, printing::RESET);
+    debugging::print_warnings(&semantic_data.warnings, source_files);
+    return SemanticResult {
+        unused_variables: semantic_data.unused_variables,
+        generic_constraints: semantic_data.generic_constraints,
+    };
+}
+
+/// The state the checks share, nothing is checked yet
+pub fn create_semantic_data<'lifetimes_suck>(ast_nodes:&'lifetimes_suck Vec<ASTNode>, source_files:&Vec<SourceFile>, options:&CompileOptions) -> SemanticData<'lifetimes_suck> {
+    let mut semantic_data:SemanticData = SemanticData {
+        ast_nodes,
+        error_code: SemanticError::None,
+        error_detail: String::new(),
+        error_token: structs::empty_token(),
+        error_function: String::new(),
+        symbol_table: SymbolTable::new(),
+        source_paths: Vec::new(),
+        function_return_type: String::new(),
+        warnings: Vec::new(),
+        unused_variable_mode: options.unused_variable_mode,
+        unused_variables: Vec::new(),
+        uninitialized_mode: options.uninitialized_mode,
+        init_variables: Vec::new(),
+        init_loops: Vec::new(),
+        generic_constraints: Vec::new(),
+    };
+
+    let source_count: usize = source_files.len();
+    for i in 0..source_count {
+        semantic_data.source_paths.push(source_files[i].path.clone());
+    }
+
+    return semantic_data;
+}
+
+/// Every check analyse runs, in order, stopping at the first error
+pub fn check_program(semantic_data:&mut SemanticData) {
+
+    //functions and structs can be used before they are declared
+    register_global_symbols(semantic_data);
+    if semantic_data.is_error() == false {
+        check_field_types(semantic_data);
+    }
+    if semantic_data.is_error() == false {
+        check_global_declarations(semantic_data);
+    }
+    //a call's type arguments are checked against the constraints of the function it calls
+    if semantic_data.is_error() == false {
+        semantic_generics::collect_generic_constraints(semantic_data);
+    }
+
+    let ast_nodes:&Vec<ASTNode> = semantic_data.ast_nodes;
+    let node_count: usize = ast_nodes.len();
+    for i in 0..node_count {
+        if semantic_data.is_error() {
+            break;
+        }
+        let node: &ASTNode = &ast_nodes[i];
+        if node.node_type != ASTNodeType::FunctionDeclaration {
+            continue;
+        }
+        semantic_names::check_function(semantic_data, node);
+        if semantic_data.is_error() {
+            break;
+        }
+        semantic_init::check_function_init(semantic_data, node);
+        if semantic_data.is_error() {
+            break;
+        }
+        semantic_flow::check_function_flow(semantic_data, node);
+    }
+    if semantic_data.is_error() == false {
+        warn_unused_functions(semantic_data);
+    }
+}
+
+// Go compiles functions nobody calls, main is called by Go itself
+fn warn_unused_functions(semantic_data:&mut SemanticData) {
+    let symbol_count: usize = semantic_data.symbol_table.symbols.len();
+    for i in 0..symbol_count {
+        let symbol: Symbol = semantic_data.symbol_table.symbols[i].clone();
+        if symbol.symbol_kind != SymbolKind::Function || symbol.is_used || symbol.name == "main" {
+            continue;
+        }
+        semantic_data.add_warning(&symbol.token, format!("function '{}' is never used", symbol.name));
+    }
+}
+
+// Once every struct is known, so fields can hold structs declared after them
+fn check_field_types(semantic_data:&mut SemanticData) {
+    let ast_nodes: &Vec<ASTNode> = semantic_data.ast_nodes;
+    let node_count: usize = ast_nodes.len();
+    for i in 0..node_count {
+        let node: &ASTNode = &ast_nodes[i];
+        if node.node_type != ASTNodeType::StructDeclaration {
+            continue;
+        }
+        let field_count: usize = node.children.len();
+        for field_index in 0..field_count {
+            semantic_names::check_type_node(semantic_data, node.children[field_index].left);
+            if semantic_data.is_error() {
+                return;
+            }
+            semantic_types::check_field_type(semantic_data, &node.children[field_index]);
+            if semantic_data.is_error() {
+                return;
+            }
+        }
+    }
+}
+
+// In the order they are declared, imports first, so a global's value only uses the globals above it
+fn check_global_declarations(semantic_data:&mut SemanticData) {
+
+    let ast_nodes: &Vec<ASTNode> = semantic_data.ast_nodes;
+    let node_count: usize = ast_nodes.len();
+    for i in 0..node_count {
+        let node: &ASTNode = &ast_nodes[i];
+        if node.node_type != ASTNodeType::Declaration || node.right.is_null() {
+            continue;
+        }
+        semantic_data.error_function = "checkGlobalDeclarations".to_string();
+        unsafe {
+            let value_node: &ASTNode = &*node.right;
+            //the statements a ternary becomes can't go outside a function in Go
+            if go_ternary_free(value_node) == false {
+                semantic_data.error_token = (*node.token).clone();
+                semantic_data.error_detail = format!(
+                    "the value of global '{}' can't use a ternary, give it the value in main instead",
+                    (*node.token).text
+                );
+                semantic_data.error_code = SemanticError::InvalidGlobal;
+                return;
+            }
+
+            let mut later_names: Vec<String> = Vec::new();
+            for later_index in i..node_count {
+                let later_node: &ASTNode = &ast_nodes[later_index];
+                if later_node.node_type == ASTNodeType::Declaration && later_node.token.is_null() == false {
+                    later_names.push((*later_node.token).text.clone());
+                }
+            }
+            let later_use: *const ASTNode = find_identifier(value_node, &later_names);
+            if later_use.is_null() == false {
+                let use_token: Token = (*(*later_use).token).clone();
+                let declaration: Symbol = semantic_data.symbol_table.get_symbol(&use_token.text);
+                semantic_data.error_token = use_token.clone();
+                semantic_data.error_detail = format!(
+                    "'{}' is declared at {}, the value of global '{}' can only use the globals above it",
+                    use_token.text,
+                    semantic_data.get_source_location(&declaration.token),
+                    (*node.token).text
+                );
+                semantic_data.error_code = SemanticError::InvalidGlobal;
+                return;
+            }
+        }
+        semantic_names::check_global_declaration(semantic_data, node);
+        if semantic_data.is_error() {
+            return;
+        }
+    }
+}
+
+fn go_ternary_free(node: &ASTNode) -> bool {
+    if node.node_type == ASTNodeType::Ternary {
+        return false;
+    }
+    let sides: [*mut ASTNode; 3] = [node.left, node.middle, node.right];
+    for i in 0..sides.len() {
+        unsafe {
+            if sides[i].is_null() == false && go_ternary_free(&*sides[i]) == false {
+                return false;
+            }
+        }
+    }
+    let child_count: usize = node.children.len();
+    for i in 0..child_count {
+        if go_ternary_free(&node.children[i]) == false {
+            return false;
+        }
+    }
+    return true;
+}
+
+// The first Identifier in the expression that reads one of names, null if there is none
+fn find_identifier(node: &ASTNode, names: &Vec<String>) -> *const ASTNode {
+    if node.token.is_null() {
+        return std::ptr::null();
+    }
+    unsafe {
+        if node.node_type == ASTNodeType::Identifier && names.contains(&(*node.token).text) {
+            return node as *const ASTNode;
+        }
+        //a cast's left is its type, a field's name belongs to the struct
+        let mut sides: Vec<*mut ASTNode> = vec![node.left, node.middle, node.right];
+        if node.node_type == ASTNodeType::Cast {
+            sides = vec![node.right];
+        }
+        for i in 0..sides.len() {
+            if sides[i].is_null() {
+                continue;
+            }
+            let found: *const ASTNode = find_identifier(&*sides[i], names);
+            if found.is_null() == false {
+                return found;
+            }
+        }
+    }
+    let child_count: usize = node.children.len();
+    for i in 0..child_count {
+        let found: *const ASTNode = find_identifier(&node.children[i], names);
+        if found.is_null() == false {
+            return found;
+        }
+    }
+    return std::ptr::null();
+}
+
+fn register_global_symbols(semantic_data:&mut SemanticData) {
+
+    semantic_data.error_function = "registerGlobalSymbols".to_string();
+
+    let ast_nodes: &Vec<ASTNode> = semantic_data.ast_nodes;
+    let node_count: usize = ast_nodes.len();
+
+    for i in 0..node_count {
+        let node: &ASTNode = &ast_nodes[i];
+        if node.token.is_null() {
+            continue;
+        }
+        unsafe {
+            let name_token: Token = (*node.token).clone();
+            let existing: Symbol = semantic_data.symbol_table.get_symbol(&name_token.text);
+
+            let is_global_name: bool = node.node_type == ASTNodeType::FunctionDeclaration
+                || node.node_type == ASTNodeType::Declaration
+                || node.node_type == ASTNodeType::StructDeclaration;
+            if is_global_name {
+                semantic_names::check_go_name(semantic_data, &name_token);
+                if semantic_data.is_error() {
+                    return;
+                }
+                semantic_data.error_function = "registerGlobalSymbols".to_string();
+            }
+
+            if node.node_type == ASTNodeType::FunctionDeclaration {
+                if existing.symbol_kind != SymbolKind::None {
+                    semantic_data.error_token = name_token.clone();
+                    semantic_data.error_detail = format!(
+                        "'{}' is already declared at {}",
+                        name_token.text,
+                        semantic_data.get_source_location(&existing.token)
+                    );
+                    semantic_data.error_code = SemanticError::FunctionRedefinition;
+                    return;
+                }
+                let type_text: String = type_utils::function_declaration_type_text(node);
+                let type_parameters: Vec<String> = type_utils::get_type_parameter_names(node);
+                semantic_data.symbol_table.add_function_symbol(&name_token.text, &type_text, name_token.clone(), type_parameters);
+                continue;
+            }
+            if node.node_type == ASTNodeType::Declaration {
+                if existing.symbol_kind != SymbolKind::None {
+                    semantic_data.error_token = name_token.clone();
+                    semantic_data.error_detail = format!(
+                        "'{}' is already declared at {}",
+                        name_token.text,
+                        semantic_data.get_source_location(&existing.token)
+                    );
+                    semantic_data.error_code = SemanticError::VariableRedefinition;
+                    return;
+                }
+                let type_text: String = type_utils::type_node_to_string(node.left);
+                semantic_data.symbol_table.add_symbol(&name_token.text, &type_text, SymbolKind::Variable, name_token.clone(), node.is_const);
+                continue;
+            }
+            if node.node_type != ASTNodeType::StructDeclaration {
+                continue;
+            }
+
+            if existing.symbol_kind != SymbolKind::None {
+                semantic_data.error_token = name_token.clone();
+                semantic_data.error_detail = format!(
+                    "'{}' is already declared at {}",
+                    name_token.text,
+                    semantic_data.get_source_location(&existing.token)
+                );
+                semantic_data.error_code = SemanticError::TypeRedefinition;
+                return;
+            }
+            semantic_data.symbol_table.add_symbol(&name_token.text, &name_token.text, SymbolKind::Struct, name_token.clone(), true);
+
+            //fields are looked up as "Struct.field"
+            let field_count: usize = node.children.len();
+            for field_index in 0..field_count {
+                let field: &ASTNode = &node.children[field_index];
+                let field_token: Token = (*field.token).clone();
+                let field_name: String = type_utils::get_field_symbol_name(&name_token.text, &field_token.text);
+
+                if token_utils::is_go_keyword(&field_token.text) {
+                    semantic_data.error_token = field_token.clone();
+                    semantic_data.error_detail = format!("'{}' is a Go keyword, the generated struct can't have a field with that name", field_token.text);
+                    semantic_data.error_code = SemanticError::ReservedName;
+                    return;
+                }
+
+                let existing_field: Symbol = semantic_data.symbol_table.get_symbol(&field_name);
+                if existing_field.symbol_kind != SymbolKind::None {
+                    semantic_data.error_token = field_token.clone();
+                    semantic_data.error_detail = format!(
+                        "struct '{}' already has a field '{}' at {}",
+                        name_token.text,
+                        field_token.text,
+                        semantic_data.get_source_location(&existing_field.token)
+                    );
+                    semantic_data.error_code = SemanticError::VariableRedefinition;
+                    return;
+                }
+
+                let field_type: String = type_utils::type_node_to_string(field.left);
+                semantic_data.symbol_table.add_symbol(&field_name, &field_type, SymbolKind::Field, field_token, false);
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::SemanticData;
+use structs::Symbol;
+use structs::Token;
+
+// A const variable can't be changed, and neither can anything reached through it:
+// "const *Point p" makes p.x read only, "const i32[] a" makes a[0] read only.
+// The language has no & or * expressions, so fields and indexes are the only ways through a pointer.
+
+fn set_const_error(semantic_data:&mut SemanticData, token:&Token, detail:String) {
+    semantic_data.error_token = token.clone();
+    semantic_data.error_detail = detail;
+    semantic_data.error_code = SemanticError::ConstViolation;
+}
+
+/// Assignment, compound assignment, ++ and -- can't change a const variable or write through one
+pub fn check_assignment_const(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkAssignmentConst".to_string();
+
+    //Node_type: Assignment
+    //Token:     assignment operator, ++ or --
+    //Left:      variable being assigned to
+
+    if node.left.is_null() {
+        return;
+    }
+    unsafe {
+        let operator_token:Token = (*node.token).clone();
+        let target_node:&ASTNode = &*node.left;
+        let root_node:*const ASTNode = get_root_identifier(target_node);
+        if root_node.is_null() {
+            return;
+        }
+        let root_token:Token = (*(*root_node).token).clone();
+        let symbol:Symbol = semantic_data.symbol_table.get_symbol(&root_token.text);
+        if is_const_value(&symbol) == false {
+            return;
+        }
+
+        let location:String = semantic_data.get_source_location(&symbol.token);
+        if target_node.node_type == ASTNodeType::Identifier {
+            set_const_error(semantic_data, &operator_token, format!(
+                "cannot use '{}' on '{}', it is declared const at {}",
+                operator_token.text, root_token.text, location
+            ));
+            return;
+        }
+        set_const_error(semantic_data, &operator_token, format!(
+            "cannot use '{}' to change what '{}' holds, it is declared const '{}' at {}",
+            operator_token.text, root_token.text, symbol.type_text, location
+        ));
+    }
+}
+
+/// A const pointer can't be handed to a parameter that would let the function write through it
+pub fn check_call_const(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkCallConst".to_string();
+
+    //Node_type: FunctionCall
+    //Left:      the function being called, a name or a function value
+    //Children:  arguments
+
+    if node.left.is_null() {
+        return;
+    }
+    unsafe {
+        let callee:&ASTNode = &*node.left;
+        if callee.node_type != ASTNodeType::Identifier {
+            return;
+        }
+        let function_name:String = (*callee.token).text.clone();
+        let function_symbol:Symbol = semantic_data.symbol_table.get_symbol(&function_name);
+        if function_symbol.symbol_kind != SymbolKind::Function {
+            return;
+        }
+        let declaration_ptr:*const ASTNode = semantic_data.get_function_declaration(&function_name);
+        if declaration_ptr.is_null() || (*declaration_ptr).middle.is_null() {
+            return;
+        }
+
+        //Node_type: Parameters
+        //Children:  Parameter nodes, each with its type on the left
+        let parameters:&ASTNode = &*(*declaration_ptr).middle;
+        let argument_count:usize = node.children.len();
+        for i in 0..argument_count {
+            if i >= parameters.children.len() {
+                return;
+            }
+            let argument:&ASTNode = &node.children[i];
+            if argument.node_type != ASTNodeType::Identifier {
+                continue;
+            }
+            let argument_token:Token = (*argument.token).clone();
+            let symbol:Symbol = semantic_data.symbol_table.get_symbol(&argument_token.text);
+            if is_const_value(&symbol) == false || symbol.type_text.starts_with('*') == false {
+                continue;
+            }
+
+            let parameter:&ASTNode = &parameters.children[i];
+            if parameter.left.is_null() || (*parameter.left).is_const {
+                continue;
+            }
+            let parameter_token:Token = (*parameter.token).clone();
+            set_const_error(semantic_data, &argument_token, format!(
+                "cannot pass const '{}' to '{}' parameter '{}' of type '{}', declare the parameter 'const {}'",
+                argument_token.text, function_name, parameter_token.text, symbol.type_text, symbol.type_text
+            ));
+            return;
+        }
+    }
+}
+
+// Variables and parameters can be const, functions always are but aren't values to change
+fn is_const_value(symbol:&Symbol) -> bool {
+    let is_value:bool = symbol.symbol_kind == SymbolKind::Variable || symbol.symbol_kind == SymbolKind::Parameter;
+    return is_value && symbol.is_const;
+}
+
+// The variable an assignment target starts from, p for p.items[0].x, null for a call's result
+fn get_root_identifier(node:&ASTNode) -> *const ASTNode {
+    let mut current:*const ASTNode = node as *const ASTNode;
+    unsafe {
+        while current.is_null() == false {
+            match (*current).node_type {
+                ASTNodeType::Identifier => return current,
+                ASTNodeType::FieldAccess | ASTNodeType::ArrayAccess => current = (*current).left,
+                _ => return std::ptr::null(),
+            }
+        }
+    }
+    return std::ptr::null();
+}
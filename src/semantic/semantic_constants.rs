@@ -0,0 +1,192 @@
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::TokenType;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::SemanticData;
+use structs::Token;
+
+// Go works integer constants out exactly and refuses one that doesn't fit the type it ends up with,
+// e.g. "var x uint8 = 300". Constants are folded the same way here so the mistake is reported
+// against the source, with the value and the range the type allows.
+
+/// A value given to a typed slot: a declaration, an assignment, a return or an argument
+pub fn check_constant_fits(semantic_data:&mut SemanticData, value:&ASTNode, type_text:&str) {
+
+    semantic_data.error_function = "checkConstantFits".to_string();
+
+    //each branch of a ternary is given to the slot on its own
+    if value.node_type == ASTNodeType::Ternary {
+        check_optional_constant_fits(semantic_data, value.middle, type_text);
+        if semantic_data.is_error() == false {
+            check_optional_constant_fits(semantic_data, value.right, type_text);
+        }
+        return;
+    }
+
+    let type_token_type:i32 = type_utils::get_type_text_token_type(type_text);
+    if token_utils::is_integer_var_type(type_token_type) == false {
+        return;
+    }
+    let mut constant:i128 = 0;
+    if fold_integer_constant(semantic_data, value, &mut constant) == false {
+        return;
+    }
+
+    let (min, max):(i128, i128) = token_utils::get_integer_range(type_token_type);
+    if constant >= min && constant <= max {
+        return;
+    }
+    unsafe {
+        let value_token:Token = (*value.token).clone();
+        semantic_data.error_token = value_token;
+        semantic_data.error_detail = format!("constant {} overflows '{}', which holds {} to {}", constant, type_text, min, max);
+        semantic_data.error_code = SemanticError::IntegerOverflow;
+    }
+}
+
+fn check_optional_constant_fits(semantic_data:&mut SemanticData, value_ptr:*mut ASTNode, type_text:&str) {
+    if value_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        check_constant_fits(semantic_data, &*value_ptr, type_text);
+    }
+}
+
+/// Constants inside an expression that take a type from around them: arguments, casts and operands
+pub fn check_expression_constants(semantic_data:&mut SemanticData, node:&ASTNode) {
+    if node.token.is_null() {
+        return;
+    }
+    unsafe {
+        match node.node_type {
+            ASTNodeType::FunctionCall => {
+                let function_type:String = type_utils::get_call_function_type(node, &semantic_data.symbol_table);
+                let parameter_types:Vec<String> = type_utils::get_function_parameter_types(&function_type);
+                let argument_count:usize = node.children.len();
+                for i in 0..argument_count {
+                    if i >= parameter_types.len() || semantic_data.is_error() {
+                        return;
+                    }
+                    check_constant_fits(semantic_data, &node.children[i], &parameter_types[i]);
+                }
+            }
+            //u8(300) is refused like u8 x = 300
+            ASTNodeType::Cast => {
+                let target_type:String = type_utils::type_node_to_string(node.left);
+                check_optional_constant_fits(semantic_data, node.right, &target_type);
+            }
+            _ => {
+                if type_utils::is_binary_node(node) == false {
+                    return;
+                }
+                //x + 300 makes the 300 whatever type x is
+                let left_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+                let right_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+                if type_utils::is_literal_type_text(&left_type) == false {
+                    check_constant_fits(semantic_data, &*node.right, &left_type);
+                }
+                if semantic_data.is_error() == false && type_utils::is_literal_type_text(&right_type) == false {
+                    check_constant_fits(semantic_data, &*node.left, &right_type);
+                }
+            }
+        }
+    }
+}
+
+// True if the expression is an integer known before the program runs, its value put in constant.
+// Too big to work out is left to Go, a division by zero is reported
+fn fold_integer_constant(semantic_data:&mut SemanticData, node:&ASTNode, constant:&mut i128) -> bool {
+    if node.token.is_null() {
+        return false;
+    }
+    unsafe {
+        let token:Token = (*node.token).clone();
+        match node.node_type {
+            ASTNodeType::IntegerLiteral => {
+                match token.text.parse::<i128>() {
+                    Ok(value) => {
+                        *constant = value;
+                        return true;
+                    }
+                    Err(_) => return false,
+                }
+            }
+            ASTNodeType::Minus => {
+                if node.left.is_null() || fold_integer_constant(semantic_data, &*node.left, constant) == false {
+                    return false;
+                }
+                *constant = -*constant;
+                return true;
+            }
+            _ => {}
+        }
+        if type_utils::is_binary_node(node) == false {
+            return false;
+        }
+
+        let mut left:i128 = 0;
+        let mut right:i128 = 0;
+        if fold_integer_constant(semantic_data, &*node.left, &mut left) == false {
+            return false;
+        }
+        if fold_integer_constant(semantic_data, &*node.right, &mut right) == false {
+            return false;
+        }
+
+        let is_division:bool = token.token_type == TokenType::Divide || token.token_type == TokenType::Modulus;
+        if is_division && right == 0 {
+            semantic_data.error_token = token.clone();
+            semantic_data.error_detail = format!("'{} {} 0' divides by zero", left, token.text);
+            semantic_data.error_code = SemanticError::IntegerOverflow;
+            return false;
+        }
+
+        let folded:Option<i128> = match token.token_type {
+            TokenType::Plus => left.checked_add(right),
+            TokenType::Minus => left.checked_sub(right),
+            TokenType::Multiply => left.checked_mul(right),
+            //Go's integer division truncates towards zero like Rust's
+            TokenType::Divide => left.checked_div(right),
+            TokenType::Modulus => left.checked_rem(right),
+            _ => None,
+        };
+        match folded {
+            Some(value) => {
+                *constant = value;
+                return true;
+            }
+            None => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::errors::SemanticError;
+    use crate::test_utils;
+
+    #[test]
+    fn constant_above_the_range() {
+        let code:String = "fn void main() {\n    u8 x = 300;\n    println(x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::IntegerOverflow);
+        assert_eq!(test_utils::get_semantic_error_detail(&code), "constant 300 overflows 'u8', which holds 0 to 255");
+    }
+
+    #[test]
+    fn constant_below_the_range() {
+        let code:String = "fn void main() {\n    i8 x = -129;\n    println(x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::IntegerOverflow);
+    }
+
+    #[test]
+    fn constants_at_the_ends_of_the_range() {
+        let code:String = "fn void main() {\n    i8 x = -128;\n    u8 y = 255;\n    println(x);\n    println(y);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::None);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::core::builtins;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::SemanticData;
+use structs::Token;
+
+// A statement is terminating when nothing after it can run, the same rule Go uses for
+// "missing return": return, panic, an if whose every branch terminates, or a loop with
+// no condition that nothing breaks out of.
+
+/// A non-void function must not reach its end, statements after an exit in the same block are warned about
+pub fn check_function_flow(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkFunctionFlow".to_string();
+
+    //Node_type: FunctionDeclaration
+    //Token:     function name
+    //Left:      return type
+    //Right:     FunctionBody
+
+    if node.right.is_null() {
+        return;
+    }
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        let return_type:String = type_utils::type_node_to_string(node.left);
+
+        let is_terminating:bool = check_block_flow(semantic_data, &*node.right);
+        if return_type == type_utils::VOID_TYPE || is_terminating {
+            return;
+        }
+        semantic_data.error_token = name_token.clone();
+        semantic_data.error_detail = format!(
+            "'{}' returns '{}' but can reach the end of its body without a return",
+            name_token.text, return_type
+        );
+        semantic_data.error_code = SemanticError::MissingReturn;
+    }
+}
+
+// Every statement in a block, true if the block terminates
+fn check_block_flow(semantic_data:&mut SemanticData, body:&ASTNode) -> bool {
+    let mut is_terminating:bool = false;
+    let mut exit_token:Token = structs::empty_token();
+
+    let child_count:usize = body.children.len();
+    for i in 0..child_count {
+        let child:&ASTNode = &body.children[i];
+
+        //only the first statement that can't run is reported, the rest follow from it
+        if exit_token.text.is_empty() == false {
+            let child_token:Token = get_statement_token(child);
+            semantic_data.add_warning(&child_token, format!("unreachable code after '{}'", exit_token.text));
+            exit_token = structs::empty_token();
+        }
+
+        if check_statement_flow(semantic_data, child, "") {
+            is_terminating = true;
+        }
+        if is_exit_statement(semantic_data, child) && i + 1 < child_count {
+            unsafe {
+                exit_token = (*child.token).clone();
+            }
+        }
+    }
+    return is_terminating;
+}
+
+// return, break, continue and panic leave the block there and then
+fn is_exit_statement(semantic_data:&SemanticData, node:&ASTNode) -> bool {
+    match node.node_type {
+        ASTNodeType::Return | ASTNodeType::Break | ASTNodeType::Continue => return true,
+        _ => return is_panic_call(semantic_data, node),
+    }
+}
+
+fn is_panic_call(semantic_data:&SemanticData, node:&ASTNode) -> bool {
+    if builtins::is_builtin_call(node, &semantic_data.symbol_table) == false {
+        return false;
+    }
+    unsafe {
+        return (*node.token).text == builtins::PANIC;
+    }
+}
+
+// Where a warning about the statement points, declarations and assignments start before their own token
+fn get_statement_token(node:&ASTNode) -> Token {
+    unsafe {
+        let is_declaration:bool = node.node_type == ASTNodeType::Declaration || node.node_type == ASTNodeType::Assignment;
+        if is_declaration && node.left.is_null() == false && (*node.left).token.is_null() == false {
+            return (*(*node.left).token).clone();
+        }
+        return (*node.token).clone();
+    }
+}
+
+// label is the name the statement was given, empty without one
+fn check_statement_flow(semantic_data:&mut SemanticData, node:&ASTNode, label:&str) -> bool {
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Return => return true,
+            ASTNodeType::FunctionCall => return is_panic_call(semantic_data, node),
+            ASTNodeType::Label => {
+                if node.left.is_null() {
+                    return false;
+                }
+                let label_name:String = (*node.token).text.clone();
+                return check_statement_flow(semantic_data, &*node.left, &label_name);
+            }
+            ASTNodeType::IfStatement => return check_if_flow(semantic_data, node),
+            ASTNodeType::WhileLoop => {
+                check_optional_block_flow(semantic_data, node.right);
+                return is_endless_loop(node.left) && has_break_to(node.right, label, false) == false;
+            }
+            ASTNodeType::ForLoop => {
+                check_optional_block_flow(semantic_data, node.right);
+                let mut condition_ptr:*mut ASTNode = std::ptr::null_mut();
+                if node.left.is_null() == false {
+                    condition_ptr = (*node.left).middle;
+                }
+                return is_endless_loop(condition_ptr) && has_break_to(node.right, label, false) == false;
+            }
+            //written with a break for its condition, so it never terminates
+            ASTNodeType::DoWhileLoop => {
+                check_optional_block_flow(semantic_data, node.right);
+                return false;
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn check_optional_block_flow(semantic_data:&mut SemanticData, body_ptr:*mut ASTNode) -> bool {
+    if body_ptr.is_null() {
+        return false;
+    }
+    unsafe {
+        return check_block_flow(semantic_data, &*body_ptr);
+    }
+}
+
+fn check_if_flow(semantic_data:&mut SemanticData, node:&ASTNode) -> bool {
+
+    //Node_type: IfStatement
+    //Middle:    IfBody
+    //Right:     Else, null without one
+
+    unsafe {
+        let if_terminates:bool = check_optional_block_flow(semantic_data, node.middle);
+        if node.right.is_null() {
+            return false;
+        }
+
+        //Node_type: Else
+        //Left:      IfStatement for else if, otherwise ElseBody
+        let else_node:&ASTNode = &*node.right;
+        if else_node.left.is_null() {
+            return false;
+        }
+        let else_terminates:bool = if (*else_node.left).node_type == ASTNodeType::IfStatement {
+            check_if_flow(semantic_data, &*else_node.left)
+        } else {
+            check_optional_block_flow(semantic_data, else_node.left)
+        };
+        return if_terminates && else_terminates;
+    }
+}
+
+// No condition or a literal true, convert writes both as Go's bare for
+fn is_endless_loop(condition_ptr:*mut ASTNode) -> bool {
+    if condition_ptr.is_null() {
+        return true;
+    }
+    unsafe {
+        return type_utils::is_true_literal(&*condition_ptr);
+    }
+}
+
+// True if a break in the body leaves the loop, is_nested once inside another loop an unlabeled break stops there
+fn has_break_to(body_ptr:*mut ASTNode, label:&str, is_nested:bool) -> bool {
+    if body_ptr.is_null() {
+        return false;
+    }
+    unsafe {
+        let body:&ASTNode = &*body_ptr;
+        let child_count:usize = body.children.len();
+        for i in 0..child_count {
+            if statement_breaks_to(&body.children[i], label, is_nested) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+fn statement_breaks_to(node:&ASTNode, label:&str, is_nested:bool) -> bool {
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Break => {
+                if node.left.is_null() {
+                    return is_nested == false;
+                }
+                return label.is_empty() == false && (*(*node.left).token).text == label;
+            }
+            ASTNodeType::Label => {
+                if node.left.is_null() {
+                    return false;
+                }
+                return statement_breaks_to(&*node.left, label, is_nested);
+            }
+            ASTNodeType::IfStatement => {
+                if has_break_to(node.middle, label, is_nested) {
+                    return true;
+                }
+                if node.right.is_null() || (*node.right).left.is_null() {
+                    return false;
+                }
+                let else_body:&ASTNode = &*(*node.right).left;
+                if else_body.node_type == ASTNodeType::IfStatement {
+                    return statement_breaks_to(else_body, label, is_nested);
+                }
+                return has_break_to((*node.right).left, label, is_nested);
+            }
+            ASTNodeType::WhileLoop | ASTNodeType::ForLoop | ASTNodeType::DoWhileLoop => {
+                return has_break_to(node.right, label, true);
+            }
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use enums::TokenType;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::GenericConstraints;
+use structs::SemanticData;
+use structs::Symbol;
+use structs::Token;
+
+// Go checks a type argument against its type parameter's constraint, e.g. sub<string> against
+// [T __Number]. The constraints are worked out here from what the bodies do with each type
+// parameter, so a type argument that doesn't fit is reported against the source and convert
+// only has to write them.
+
+/// Works out every generic function's constraints before any call is checked.
+/// wrap<U> calling big<U> needs whatever big needs of its T, so calls raise the caller's
+/// constraints to the callee's, again until nothing changes
+pub fn collect_generic_constraints(semantic_data:&mut SemanticData) {
+
+    semantic_data.error_function = "collectGenericConstraints".to_string();
+
+    let ast_nodes:&Vec<ASTNode> = semantic_data.ast_nodes;
+    let mut generic_nodes:Vec<&ASTNode> = Vec::new();
+    let node_count:usize = ast_nodes.len();
+    for i in 0..node_count {
+        let node:&ASTNode = &ast_nodes[i];
+        if node.node_type != ASTNodeType::FunctionDeclaration || node.token.is_null() {
+            continue;
+        }
+        let type_parameters:Vec<String> = type_utils::get_type_parameter_names(node);
+        if type_parameters.len() == 0 {
+            continue;
+        }
+        let constraints:Vec<usize> = get_body_constraints(semantic_data, node, &type_parameters);
+        unsafe {
+            semantic_data.generic_constraints.push(GenericConstraints {
+                function_name: (*node.token).text.clone(),
+                type_parameters,
+                constraints,
+            });
+        }
+        generic_nodes.push(node);
+    }
+
+    //every pass only raises constraints and they have a highest value, so this ends
+    let generic_count:usize = generic_nodes.len();
+    let mut is_changed:bool = true;
+    while is_changed {
+        is_changed = false;
+        for i in 0..generic_count {
+            if generic_nodes[i].right.is_null() {
+                continue;
+            }
+            let type_parameters:Vec<String> = semantic_data.generic_constraints[i].type_parameters.clone();
+            let mut constraints:Vec<usize> = semantic_data.generic_constraints[i].constraints.clone();
+            unsafe {
+                collect_call_constraints(semantic_data, &*generic_nodes[i].right, &type_parameters, &mut constraints);
+            }
+            if constraints != semantic_data.generic_constraints[i].constraints {
+                semantic_data.generic_constraints[i].constraints = constraints;
+                is_changed = true;
+            }
+        }
+    }
+}
+
+// What the function's own body does with each type parameter
+fn get_body_constraints(semantic_data:&mut SemanticData, node:&ASTNode, type_parameters:&Vec<String>) -> Vec<usize> {
+    let mut constraints:Vec<usize> = vec![type_utils::CONSTRAINT_ANY; type_parameters.len()];
+
+    //a scratch scope so the body's types can be worked out before it is checked
+    semantic_data.symbol_table.enter_scope();
+    let parameter_count:usize = type_parameters.len();
+    for i in 0..parameter_count {
+        semantic_data.symbol_table.add_symbol(&type_parameters[i], &type_parameters[i], SymbolKind::TypeParameter, structs::empty_token(), true);
+    }
+    unsafe {
+        if node.middle.is_null() == false {
+            let parameters:&ASTNode = &*node.middle;
+            let child_count:usize = parameters.children.len();
+            for i in 0..child_count {
+                let parameter:&ASTNode = &parameters.children[i];
+                if parameter.token.is_null() {
+                    continue;
+                }
+                let type_text:String = type_utils::type_node_to_string(parameter.left);
+                let parameter_token:Token = (*parameter.token).clone();
+                semantic_data.symbol_table.add_symbol(&parameter_token.text, &type_text, SymbolKind::Parameter, parameter_token.clone(), false);
+            }
+        }
+        if node.right.is_null() == false {
+            collect_constraints(semantic_data, &*node.right, type_parameters, &mut constraints);
+        }
+    }
+    semantic_data.symbol_table.exit_scope();
+    return constraints;
+}
+
+// big<U>(a, b) gives U whatever big needs of its first type parameter
+fn collect_call_constraints(semantic_data:&SemanticData, node:&ASTNode, type_parameters:&Vec<String>, constraints:&mut Vec<usize>) {
+    unsafe {
+        if node.node_type == ASTNodeType::FunctionCall && node.left.is_null() == false && node.middle.is_null() == false {
+            let function_node:&ASTNode = &*node.left;
+            if function_node.node_type == ASTNodeType::Identifier {
+                let callee_index:usize = type_utils::get_generic_constraints_index(&semantic_data.generic_constraints, &(*function_node.token).text);
+                if callee_index < semantic_data.generic_constraints.len() {
+                    let callee_constraints:&Vec<usize> = &semantic_data.generic_constraints[callee_index].constraints;
+                    let type_arguments:Vec<String> = type_utils::get_type_argument_texts(node);
+                    let argument_count:usize = type_arguments.len();
+                    for i in 0..argument_count {
+                        if i >= callee_constraints.len() {
+                            break;
+                        }
+                        raise_constraint(&type_arguments[i], callee_constraints[i], type_parameters, constraints);
+                    }
+                }
+            }
+        }
+
+        if node.left.is_null() == false {
+            collect_call_constraints(semantic_data, &*node.left, type_parameters, constraints);
+        }
+        if node.middle.is_null() == false {
+            collect_call_constraints(semantic_data, &*node.middle, type_parameters, constraints);
+        }
+        if node.right.is_null() == false {
+            collect_call_constraints(semantic_data, &*node.right, type_parameters, constraints);
+        }
+    }
+
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        collect_call_constraints(semantic_data, &node.children[i], type_parameters, constraints);
+    }
+}
+
+fn get_operator_constraint(token_type:i32) -> usize {
+    match token_type {
+        TokenType::EqualsEquals | TokenType::NotEquals => return type_utils::CONSTRAINT_COMPARABLE,
+        TokenType::LessThan | TokenType::LessThanEquals | TokenType::GreaterThan | TokenType::GreaterThanEquals => return type_utils::CONSTRAINT_ORDERED,
+        TokenType::Plus => return type_utils::CONSTRAINT_ORDERED,
+        TokenType::Minus | TokenType::Multiply | TokenType::Divide => return type_utils::CONSTRAINT_NUMBER,
+        TokenType::Modulus => return type_utils::CONSTRAINT_INTEGER,
+        _ => return type_utils::CONSTRAINT_ANY,
+    }
+}
+
+fn raise_constraint(type_text:&str, constraint:usize, type_parameters:&Vec<String>, constraints:&mut Vec<usize>) {
+    let parameter_count:usize = type_parameters.len();
+    for i in 0..parameter_count {
+        if type_parameters[i] == type_text && constraints[i] < constraint {
+            constraints[i] = constraint;
+        }
+    }
+}
+
+fn collect_constraints(semantic_data:&mut SemanticData, node:&ASTNode, type_parameters:&Vec<String>, constraints:&mut Vec<usize>) {
+    unsafe {
+        if node.node_type == ASTNodeType::Declaration {
+            if node.right.is_null() == false {
+                collect_constraints(semantic_data, &*node.right, type_parameters, constraints);
+            }
+            if node.token.is_null() {
+                return;
+            }
+            let name_token:Token = (*node.token).clone();
+            let type_text:String = type_utils::type_node_to_string(node.left);
+            semantic_data.symbol_table.add_symbol(&name_token.text, &type_text, SymbolKind::Variable, name_token.clone(), node.is_const);
+            return;
+        }
+
+        if node.node_type == ASTNodeType::Minus && node.left.is_null() == false {
+            let value_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+            raise_constraint(&value_type, type_utils::CONSTRAINT_NUMBER, type_parameters, constraints);
+        }
+
+        if type_utils::is_binary_node(node) {
+            let left_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+            let right_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+            let operand_type:String = type_utils::get_operand_type(&left_type, &right_type);
+            let mut constraint:usize = get_operator_constraint((*node.token).token_type);
+            //an untyped number only fits a type parameter limited to numbers
+            let has_literal:bool = left_type == type_utils::INTEGER_LITERAL_TYPE || right_type == type_utils::INTEGER_LITERAL_TYPE;
+            if has_literal && constraint < type_utils::CONSTRAINT_NUMBER {
+                constraint = type_utils::CONSTRAINT_NUMBER;
+            }
+            raise_constraint(&operand_type, constraint, type_parameters, constraints);
+        }
+
+        if node.left.is_null() == false {
+            collect_constraints(semantic_data, &*node.left, type_parameters, constraints);
+        }
+        if node.middle.is_null() == false {
+            collect_constraints(semantic_data, &*node.middle, type_parameters, constraints);
+        }
+        if node.right.is_null() == false {
+            collect_constraints(semantic_data, &*node.right, type_parameters, constraints);
+        }
+    }
+
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        collect_constraints(semantic_data, &node.children[i], type_parameters, constraints);
+    }
+}
+
+/// Type arguments are never inferred, a generic call has to give exactly one per type parameter
+/// and each has to support what the function does with it
+pub fn check_type_arguments(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: FunctionCall
+    //Token:     function name
+    //Left:      the function being called
+    //Middle:    TypeArguments, null if none were given
+
+    semantic_data.error_function = "checkTypeArguments".to_string();
+
+    unsafe {
+        if node.left.is_null() || node.token.is_null() {
+            return;
+        }
+        let call_token:Token = (*node.token).clone();
+        let function_node:&ASTNode = &*node.left;
+
+        let mut type_parameters:Vec<String> = Vec::new();
+        if function_node.node_type == ASTNodeType::Identifier {
+            let symbol:Symbol = semantic_data.symbol_table.get_symbol(&(*function_node.token).text);
+            type_parameters = symbol.type_parameters;
+        }
+        let type_arguments:Vec<String> = type_utils::get_type_argument_texts(node);
+
+        if type_parameters.len() > 0 && node.middle.is_null() {
+            set_type_arguments_error(semantic_data, &call_token, format!(
+                "'{}' is generic, give its types explicitly, e.g. {}<{}>(...)",
+                call_token.text, call_token.text, type_parameters.join(", ")
+            ));
+            return;
+        }
+        if type_parameters.len() == 0 && node.middle.is_null() == false {
+            set_type_arguments_error(semantic_data, &call_token, format!("'{}' is not generic and takes no type arguments", call_token.text));
+            return;
+        }
+        if type_parameters.len() != type_arguments.len() {
+            set_type_arguments_error(semantic_data, &call_token, format!(
+                "'{}' takes {} type argument(s), found {}",
+                call_token.text, type_parameters.len(), type_arguments.len()
+            ));
+            return;
+        }
+
+        let generic_index:usize = type_utils::get_generic_constraints_index(&semantic_data.generic_constraints, &call_token.text);
+        if generic_index >= semantic_data.generic_constraints.len() {
+            return;
+        }
+        let constraints:Vec<usize> = semantic_data.generic_constraints[generic_index].constraints.clone();
+        let argument_count:usize = type_arguments.len();
+        for i in 0..argument_count {
+            //the caller's own type parameter was given the callee's constraint when they were collected
+            if type_utils::is_type_parameter(&type_arguments[i], &semantic_data.symbol_table) {
+                continue;
+            }
+            if type_utils::is_constraint_satisfied(&type_arguments[i], constraints[i]) {
+                continue;
+            }
+            set_type_arguments_error(semantic_data, &call_token, format!(
+                "type argument {} of '{}' must be {} for '{}', found '{}'",
+                i + 1, call_token.text, type_utils::get_constraint_text(constraints[i]), type_parameters[i], type_arguments[i]
+            ));
+            return;
+        }
+    }
+}
+
+fn set_type_arguments_error(semantic_data:&mut SemanticData, token:&Token, detail:String) {
+    semantic_data.error_token = token.clone();
+    semantic_data.error_detail = detail;
+    semantic_data.error_code = SemanticError::InvalidTypeArguments;
+}
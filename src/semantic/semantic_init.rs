@@ -0,0 +1,537 @@
+use crate::core::builtins;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::type_utils;
+use enums::ASTNodeType;
+use enums::TokenType;
+use enums::UninitializedMode;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::InitLoop;
+use structs::InitVariable;
+use structs::SemanticData;
+use structs::Token;
+
+// Go gives "i32 x;" a zero, but reading x before anything is written to it is almost always a bug.
+// Each path through the function is followed once: an if merges what both branches assigned,
+// a loop's body might not run, and a break carries what was assigned to where the loop ends.
+// A state is one is_assigned per followed variable, in declaration order.
+
+/// Reports reads of locals that not every path has given a value yet, after check_function passed
+pub fn check_function_init(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkFunctionInit".to_string();
+
+    //Node_type: FunctionDeclaration
+    //Right:     FunctionBody
+
+    semantic_data.init_variables.clear();
+    semantic_data.init_loops.clear();
+
+    //parameters always have a value and can't be redeclared at the top of the body, so they aren't followed
+    init_block(semantic_data, node.right);
+}
+
+// true if nothing after the block runs
+fn init_block(semantic_data:&mut SemanticData, body_ptr:*mut ASTNode) -> bool {
+    if body_ptr.is_null() {
+        return false;
+    }
+    let variable_count:usize = semantic_data.init_variables.len();
+    let mut is_terminating:bool = false;
+
+    unsafe {
+        let body:&ASTNode = &*body_ptr;
+        let child_count:usize = body.children.len();
+        for i in 0..child_count {
+            //the flow pass warns about anything after an exit, it never runs so it reads nothing
+            if is_terminating || semantic_data.is_error() {
+                break;
+            }
+            is_terminating = init_statement(semantic_data, &body.children[i], "");
+        }
+    }
+
+    semantic_data.init_variables.truncate(variable_count);
+    return is_terminating;
+}
+
+// label is the name the statement was given, empty without one
+fn init_statement(semantic_data:&mut SemanticData, node:&ASTNode, label:&str) -> bool {
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Print | ASTNodeType::Println => {
+                let child_count:usize = node.children.len();
+                for i in 0..child_count {
+                    read_expression(semantic_data, &node.children[i]);
+                }
+                return false;
+            }
+            ASTNodeType::Declaration => {
+                init_declaration(semantic_data, node);
+                return false;
+            }
+            ASTNodeType::MultipleDeclaration => {
+                read_optional_expression(semantic_data, node.right);
+                let declaration_count:usize = node.children.len();
+                for i in 0..declaration_count {
+                    declare_variable(semantic_data, &*node.children[i].token, true);
+                }
+                return false;
+            }
+            ASTNodeType::Assignment => {
+                init_assignment(semantic_data, node);
+                return false;
+            }
+            ASTNodeType::Return => {
+                read_optional_expression(semantic_data, node.left);
+                return true;
+            }
+            ASTNodeType::Spawn => {
+                read_optional_expression(semantic_data, node.left);
+                return false;
+            }
+            ASTNodeType::FunctionCall => {
+                read_expression(semantic_data, node);
+                let is_panic:bool = builtins::is_builtin_call(node, &semantic_data.symbol_table) && (*node.token).text == builtins::PANIC;
+                return is_panic;
+            }
+            ASTNodeType::Break | ASTNodeType::Continue => {
+                record_loop_exit(semantic_data, node);
+                return true;
+            }
+            ASTNodeType::IfStatement => return init_if(semantic_data, node),
+            ASTNodeType::WhileLoop => return init_while(semantic_data, node, label),
+            ASTNodeType::DoWhileLoop => return init_do_while(semantic_data, node, label),
+            ASTNodeType::ForLoop => return init_for(semantic_data, node, label),
+            ASTNodeType::Label => {
+                if node.left.is_null() {
+                    return false;
+                }
+                let label_name:String = (*node.token).text.clone();
+                return init_statement(semantic_data, &*node.left, &label_name);
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn init_declaration(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: Declaration
+    //Token:     variable name
+    //Left:      type node
+    //Right:     value, null if declared without one
+
+    read_optional_expression(semantic_data, node.right);
+
+    //channels and sync types are made ready by their declaration
+    let type_text:String = type_utils::type_node_to_string(node.left);
+    let is_ready:bool = type_utils::is_channel_type_text(&type_text) || type_utils::is_sync_type_text(&type_text);
+    unsafe {
+        declare_variable(semantic_data, &*node.token, node.right.is_null() == false || is_ready);
+    }
+}
+
+fn declare_variable(semantic_data:&mut SemanticData, name_token:&Token, is_assigned:bool) {
+    semantic_data.init_variables.push(InitVariable {
+        name: name_token.text.clone(),
+        token: name_token.clone(),
+        is_assigned,
+    });
+}
+
+fn init_assignment(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: Assignment
+    //Token:     assignment operator, ++ or --
+    //Left:      variable being assigned to
+    //Right:     value, null for ++ and --
+
+    if node.left.is_null() {
+        return;
+    }
+    unsafe {
+        let target:&ASTNode = &*node.left;
+        let is_plain_assignment:bool = (*node.token).token_type == TokenType::Equals;
+        if is_plain_assignment == false {
+            //x += 1 and x++ read x first
+            read_expression(semantic_data, target);
+            read_optional_expression(semantic_data, node.right);
+            return;
+        }
+
+        read_optional_expression(semantic_data, node.right);
+        if target.node_type == ASTNodeType::Identifier {
+            set_assigned(semantic_data, &(*target.token).text);
+            return;
+        }
+        //p.x = 1 fills in a struct declared without a value, a[0] = 1 needs a to already be there
+        if target.node_type == ASTNodeType::FieldAccess && target.left.is_null() == false && (*target.left).node_type == ASTNodeType::Identifier {
+            set_assigned(semantic_data, &(*(*target.left).token).text);
+            return;
+        }
+        read_expression(semantic_data, target);
+    }
+}
+
+fn init_if(semantic_data:&mut SemanticData, node:&ASTNode) -> bool {
+
+    //Node_type: IfStatement
+    //Left:      condition
+    //Middle:    IfBody
+    //Right:     Else, null without one
+
+    read_optional_expression(semantic_data, node.left);
+    let before_state:Vec<bool> = get_state(semantic_data);
+
+    let if_terminates:bool = init_block(semantic_data, node.middle);
+    let if_state:Vec<bool> = get_state(semantic_data);
+    set_state(semantic_data, &before_state);
+
+    //Node_type: Else
+    //Left:      IfStatement for else if, otherwise ElseBody
+    let mut else_terminates:bool = false;
+    unsafe {
+        if node.right.is_null() == false && (*node.right).left.is_null() == false {
+            let else_body_ptr:*mut ASTNode = (*node.right).left;
+            if (*else_body_ptr).node_type == ASTNodeType::IfStatement {
+                else_terminates = init_if(semantic_data, &*else_body_ptr);
+            } else {
+                else_terminates = init_block(semantic_data, else_body_ptr);
+            }
+        }
+    }
+    let else_state:Vec<bool> = get_state(semantic_data);
+
+    //a branch that can't reach the end doesn't decide what is assigned after the if
+    if if_terminates && else_terminates == false {
+        return false;
+    }
+    if else_terminates && if_terminates == false {
+        set_state(semantic_data, &if_state);
+        return false;
+    }
+    set_state(semantic_data, &merge_states(&if_state, &else_state));
+    return if_terminates && else_terminates;
+}
+
+fn init_while(semantic_data:&mut SemanticData, node:&ASTNode, label:&str) -> bool {
+
+    //Node_type: WhileLoop
+    //Left:      condition
+    //Right:     WhileBody
+
+    read_optional_expression(semantic_data, node.left);
+    let before_state:Vec<bool> = get_state(semantic_data);
+
+    enter_loop(semantic_data, label);
+    init_block(semantic_data, node.right);
+    let exit_loop:InitLoop = leave_loop(semantic_data);
+
+    let mut exit_states:Vec<Vec<bool>> = exit_loop.break_states;
+    if is_endless_condition(node.left) == false {
+        exit_states.push(before_state.clone());
+    }
+    return set_exit_state(semantic_data, &before_state, &exit_states);
+}
+
+fn init_for(semantic_data:&mut SemanticData, node:&ASTNode, label:&str) -> bool {
+
+    //Node_type: ForLoop
+    //Left:      ForCondition, its init, condition and step
+    //Right:     ForBody
+
+    let variable_count:usize = semantic_data.init_variables.len();
+    let mut condition_ptr:*mut ASTNode = std::ptr::null_mut();
+    let mut step_ptr:*mut ASTNode = std::ptr::null_mut();
+    unsafe {
+        if node.left.is_null() == false {
+            let condition_node:&ASTNode = &*node.left;
+            if condition_node.left.is_null() == false {
+                init_statement(semantic_data, &*condition_node.left, "");
+            }
+            condition_ptr = condition_node.middle;
+            step_ptr = condition_node.right;
+        }
+    }
+    read_optional_expression(semantic_data, condition_ptr);
+    let before_state:Vec<bool> = get_state(semantic_data);
+
+    enter_loop(semantic_data, label);
+    let body_terminates:bool = init_block(semantic_data, node.right);
+    let exit_loop:InitLoop = leave_loop(semantic_data);
+
+    //the step runs after the body's end and after every continue
+    let mut step_states:Vec<Vec<bool>> = exit_loop.continue_states;
+    if body_terminates == false {
+        step_states.push(get_state(semantic_data));
+    }
+    if step_states.is_empty() == false && step_ptr.is_null() == false {
+        set_state(semantic_data, &merge_all_states(&step_states));
+        unsafe {
+            init_statement(semantic_data, &*step_ptr, "");
+        }
+    }
+
+    let mut exit_states:Vec<Vec<bool>> = exit_loop.break_states;
+    if is_endless_condition(condition_ptr) == false {
+        exit_states.push(before_state.clone());
+    }
+    let is_terminating:bool = set_exit_state(semantic_data, &before_state, &exit_states);
+    semantic_data.init_variables.truncate(variable_count);
+    return is_terminating;
+}
+
+fn init_do_while(semantic_data:&mut SemanticData, node:&ASTNode, label:&str) -> bool {
+
+    //Node_type: DoWhileLoop
+    //Left:      condition, checked after each pass
+    //Right:     WhileBody
+
+    let before_state:Vec<bool> = get_state(semantic_data);
+
+    enter_loop(semantic_data, label);
+    let body_terminates:bool = init_block(semantic_data, node.right);
+    let exit_loop:InitLoop = leave_loop(semantic_data);
+
+    //the body always runs once, the condition follows its end and every continue
+    let mut condition_states:Vec<Vec<bool>> = exit_loop.continue_states;
+    if body_terminates == false {
+        condition_states.push(get_state(semantic_data));
+    }
+
+    let mut exit_states:Vec<Vec<bool>> = exit_loop.break_states;
+    if condition_states.is_empty() == false {
+        set_state(semantic_data, &merge_all_states(&condition_states));
+        read_optional_expression(semantic_data, node.left);
+        if is_endless_condition(node.left) == false {
+            exit_states.push(get_state(semantic_data));
+        }
+    }
+    return set_exit_state(semantic_data, &before_state, &exit_states);
+}
+
+// What is assigned once a loop is left, true if nothing leaves it
+fn set_exit_state(semantic_data:&mut SemanticData, before_state:&Vec<bool>, exit_states:&Vec<Vec<bool>>) -> bool {
+    if exit_states.is_empty() {
+        set_state(semantic_data, before_state);
+        return true;
+    }
+    set_state(semantic_data, &merge_all_states(exit_states));
+    return false;
+}
+
+// No condition or a literal true, only a break leaves the loop
+fn is_endless_condition(condition_ptr:*mut ASTNode) -> bool {
+    if condition_ptr.is_null() {
+        return true;
+    }
+    unsafe {
+        return type_utils::is_true_literal(&*condition_ptr);
+    }
+}
+
+fn enter_loop(semantic_data:&mut SemanticData, label:&str) {
+    semantic_data.init_loops.push(InitLoop {
+        label: label.to_string(),
+        break_states: Vec::new(),
+        continue_states: Vec::new(),
+    });
+}
+
+fn leave_loop(semantic_data:&mut SemanticData) -> InitLoop {
+    let loop_count:usize = semantic_data.init_loops.len();
+    let mut exit_loop:InitLoop = semantic_data.init_loops.remove(loop_count - 1);
+
+    //variables declared inside the loop are gone once it ends
+    let variable_count:usize = semantic_data.init_variables.len();
+    truncate_states(&mut exit_loop.break_states, variable_count);
+    truncate_states(&mut exit_loop.continue_states, variable_count);
+    return exit_loop;
+}
+
+fn truncate_states(states:&mut Vec<Vec<bool>>, variable_count:usize) {
+    let state_count:usize = states.len();
+    for i in 0..state_count {
+        states[i].truncate(variable_count);
+    }
+}
+
+// break and continue hand what is assigned to the loop they name, the innermost without a label
+fn record_loop_exit(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: Break or Continue
+    //Left:      Identifier naming the loop's label, null for the innermost loop
+
+    let loop_count:usize = semantic_data.init_loops.len();
+    if loop_count == 0 {
+        return;
+    }
+    let mut loop_index:usize = loop_count - 1;
+    if node.left.is_null() == false {
+        unsafe {
+            let label_name:String = (*(*node.left).token).text.clone();
+            loop_index = loop_count;
+            for i in 0..loop_count {
+                if semantic_data.init_loops[i].label == label_name {
+                    loop_index = i;
+                }
+            }
+        }
+        //convert reports a label with no loop
+        if loop_index == loop_count {
+            return;
+        }
+    }
+
+    let state:Vec<bool> = get_state(semantic_data);
+    if node.node_type == ASTNodeType::Break {
+        semantic_data.init_loops[loop_index].break_states.push(state);
+    } else {
+        semantic_data.init_loops[loop_index].continue_states.push(state);
+    }
+}
+
+fn get_state(semantic_data:&SemanticData) -> Vec<bool> {
+    let mut state:Vec<bool> = Vec::new();
+    let variable_count:usize = semantic_data.init_variables.len();
+    for i in 0..variable_count {
+        state.push(semantic_data.init_variables[i].is_assigned);
+    }
+    return state;
+}
+
+fn set_state(semantic_data:&mut SemanticData, state:&Vec<bool>) {
+    let variable_count:usize = semantic_data.init_variables.len();
+    for i in 0..variable_count {
+        if i < state.len() {
+            semantic_data.init_variables[i].is_assigned = state[i];
+        }
+    }
+}
+
+// Assigned only where both paths assigned it
+fn merge_states(first:&Vec<bool>, second:&Vec<bool>) -> Vec<bool> {
+    let mut merged:Vec<bool> = Vec::new();
+    let variable_count:usize = std::cmp::min(first.len(), second.len());
+    for i in 0..variable_count {
+        merged.push(first[i] && second[i]);
+    }
+    return merged;
+}
+
+fn merge_all_states(states:&Vec<Vec<bool>>) -> Vec<bool> {
+    let mut merged:Vec<bool> = states[0].clone();
+    let state_count:usize = states.len();
+    for i in 1..state_count {
+        merged = merge_states(&merged, &states[i]);
+    }
+    return merged;
+}
+
+fn set_assigned(semantic_data:&mut SemanticData, name:&str) {
+    let mut i:usize = semantic_data.init_variables.len();
+    while i > 0 {
+        i -= 1;
+        if semantic_data.init_variables[i].name == name {
+            semantic_data.init_variables[i].is_assigned = true;
+            return;
+        }
+    }
+}
+
+fn read_optional_expression(semantic_data:&mut SemanticData, node_ptr:*mut ASTNode) {
+    if node_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        read_expression(semantic_data, &*node_ptr);
+    }
+}
+
+fn read_expression(semantic_data:&mut SemanticData, node:&ASTNode) {
+    if node.token.is_null() || semantic_data.is_error() {
+        return;
+    }
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Identifier => read_identifier(semantic_data, &*node.token),
+            ASTNodeType::FunctionCall => {
+                read_optional_expression(semantic_data, node.left);
+                let argument_count:usize = node.children.len();
+                for i in 0..argument_count {
+                    read_expression(semantic_data, &node.children[i]);
+                }
+            }
+            ASTNodeType::FieldAccess | ASTNodeType::Minus => read_optional_expression(semantic_data, node.left),
+            ASTNodeType::Cast => read_optional_expression(semantic_data, node.right),
+            ASTNodeType::ArrayAccess | ASTNodeType::Ternary => {
+                read_optional_expression(semantic_data, node.left);
+                read_optional_expression(semantic_data, node.middle);
+                read_optional_expression(semantic_data, node.right);
+            }
+            _ => {
+                if type_utils::is_binary_node(node) {
+                    read_optional_expression(semantic_data, node.left);
+                    read_optional_expression(semantic_data, node.right);
+                }
+            }
+        }
+    }
+}
+
+fn read_identifier(semantic_data:&mut SemanticData, name_token:&Token) {
+    let mut i:usize = semantic_data.init_variables.len();
+    while i > 0 {
+        i -= 1;
+        if semantic_data.init_variables[i].name != name_token.text {
+            continue;
+        }
+        if semantic_data.init_variables[i].is_assigned {
+            return;
+        }
+        //reported once, the reads after it would only repeat the same mistake
+        semantic_data.init_variables[i].is_assigned = true;
+
+        let declaration_token:Token = semantic_data.init_variables[i].token.clone();
+        let detail:String = format!(
+            "possibly uninitialized read of '{}', it is declared without a value at {} and not every path here gives it one",
+            name_token.text,
+            semantic_data.get_source_location(&declaration_token)
+        );
+        if semantic_data.uninitialized_mode == UninitializedMode::Warning {
+            semantic_data.add_warning(name_token, detail);
+            return;
+        }
+        semantic_data.error_token = name_token.clone();
+        semantic_data.error_detail = detail;
+        semantic_data.error_code = SemanticError::UninitializedRead;
+        return;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::errors::SemanticError;
+    use crate::test_utils;
+
+    #[test]
+    fn read_before_any_value() {
+        let code:String = "fn void main() {\n    i32 x;\n    println(x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::UninitializedRead);
+    }
+
+    #[test]
+    fn read_after_every_branch_gives_a_value() {
+        let code:String = "fn void main() {\n    bool b = true;\n    i32 x;\n    if (b) {\n        x = 1;\n    } else {\n        x = 2;\n    }\n    println(x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::None);
+    }
+
+    #[test]
+    fn read_after_one_branch_gives_a_value() {
+        let code:String = "fn void main() {\n    bool b = true;\n    i32 x;\n    if (b) {\n        x = 1;\n    }\n    println(x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::UninitializedRead);
+    }
+}
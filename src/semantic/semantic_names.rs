@@ -0,0 +1,688 @@
+use crate::core::builtins;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::core::type_utils;
+use crate::parse::parse_utils;
+use crate::semantic::semantic_const;
+use crate::semantic::semantic_constants;
+use crate::semantic::semantic_types;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use enums::TokenType;
+use enums::UnusedVariableMode;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::SemanticData;
+use structs::Symbol;
+use structs::Token;
+
+pub fn check_function(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkFunction".to_string();
+
+    //Node_type: FunctionDeclaration
+    //Token:     function name
+    //Left:      return type
+    //Middle:    Parameters
+    //Right:     FunctionBody
+    //Children:  TypeParameter nodes, empty unless generic
+
+    semantic_data.function_return_type = type_utils::type_node_to_string(node.left);
+
+    //parameters share a scope with the top of the body, like Go
+    semantic_data.symbol_table.enter_scope();
+
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        let child:&ASTNode = &node.children[i];
+        if child.node_type != ASTNodeType::TypeParameter || child.token.is_null() {
+            continue;
+        }
+        unsafe {
+            let name_token:Token = (*child.token).clone();
+            declare_symbol(semantic_data, &name_token, &name_token.text, SymbolKind::TypeParameter, true);
+        }
+        if semantic_data.is_error() {
+            return;
+        }
+    }
+
+    //the signature can use the type parameters, so its types are checked once they are declared
+    check_type_node(semantic_data, node.left);
+    if semantic_data.is_error() {
+        return;
+    }
+
+    unsafe {
+        if node.middle.is_null() == false {
+            let parameters:&ASTNode = &*node.middle;
+            let parameter_count:usize = parameters.children.len();
+            for i in 0..parameter_count {
+                let parameter:&ASTNode = &parameters.children[i];
+                check_type_node(semantic_data, parameter.left);
+                if semantic_data.is_error() {
+                    return;
+                }
+                let parameter_token:Token = (*parameter.token).clone();
+                let type_text:String = type_utils::type_node_to_string(parameter.left);
+                let is_const:bool = parameter.left.is_null() == false && (*parameter.left).is_const;
+                declare_symbol(semantic_data, &parameter_token, &type_text, SymbolKind::Parameter, is_const);
+                if semantic_data.is_error() {
+                    return;
+                }
+            }
+        }
+
+        if node.right.is_null() == false {
+            check_body(semantic_data, &*node.right);
+        }
+    }
+
+    exit_scope(semantic_data, false);
+}
+
+/// Names are written to Go as they are, so one Go or the generated code already gives a meaning is an error
+pub fn check_go_name(semantic_data:&mut SemanticData, name_token:&Token) {
+
+    semantic_data.error_function = "checkGoName".to_string();
+
+    let clash:String = token_utils::get_go_name_clash(&name_token.text);
+    if clash.is_empty() {
+        return;
+    }
+    semantic_data.error_token = name_token.clone();
+    semantic_data.error_detail = clash;
+    semantic_data.error_code = SemanticError::ReservedName;
+}
+
+/// Adds a name to the innermost scope, a name already declared in that same scope is an error
+fn declare_symbol(semantic_data:&mut SemanticData, name_token:&Token, type_text:&str, symbol_kind:i32, is_const:bool) {
+
+    check_go_name(semantic_data, name_token);
+    if semantic_data.is_error() {
+        return;
+    }
+    semantic_data.error_function = "declareSymbol".to_string();
+
+    let existing:Symbol = semantic_data.symbol_table.get_scope_symbol(&name_token.text);
+    if existing.symbol_kind != SymbolKind::None {
+        semantic_data.error_token = name_token.clone();
+        semantic_data.error_detail = format!(
+            "'{}' is already declared in this scope at {}",
+            name_token.text,
+            semantic_data.get_source_location(&existing.token)
+        );
+        if symbol_kind == SymbolKind::TypeParameter {
+            semantic_data.error_code = SemanticError::TypeRedefinition;
+        } else {
+            semantic_data.error_code = SemanticError::VariableRedefinition;
+        }
+        return;
+    }
+    semantic_data.symbol_table.add_symbol(&name_token.text, type_text, symbol_kind, name_token.clone(), is_const);
+}
+
+fn check_body(semantic_data:&mut SemanticData, node:&ASTNode) {
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        check_statement(semantic_data, &node.children[i]);
+        if semantic_data.is_error() {
+            return;
+        }
+    }
+}
+
+// { body }, its declarations end with it
+fn check_block(semantic_data:&mut SemanticData, body_node_ptr:*mut ASTNode) {
+    if body_node_ptr.is_null() {
+        return;
+    }
+    semantic_data.symbol_table.enter_scope();
+    unsafe {
+        check_body(semantic_data, &*body_node_ptr);
+    }
+    exit_scope(semantic_data, false);
+}
+
+/// Reports the names in the innermost scope that were never read, then leaves it.
+/// is_loop_init for a for loop's own scope, Go has nowhere to put _ = i there
+fn exit_scope(semantic_data:&mut SemanticData, is_loop_init:bool) {
+    if semantic_data.is_error() {
+        semantic_data.symbol_table.exit_scope();
+        return;
+    }
+
+    semantic_data.error_function = "exitScope".to_string();
+
+    let scope_depth:usize = semantic_data.symbol_table.scope_depth;
+    let mut first_index:usize = semantic_data.symbol_table.symbols.len();
+    while first_index > 0 && semantic_data.symbol_table.symbols[first_index - 1].scope_depth >= scope_depth {
+        first_index -= 1;
+    }
+
+    let symbol_count:usize = semantic_data.symbol_table.symbols.len();
+    for i in first_index..symbol_count {
+        let symbol:Symbol = semantic_data.symbol_table.symbols[i].clone();
+        if symbol.is_used {
+            continue;
+        }
+        if symbol.symbol_kind == SymbolKind::Parameter {
+            //Go compiles unused parameters, they're only worth a mention
+            semantic_data.add_warning(&symbol.token, format!("parameter '{}' is never used", symbol.name));
+            continue;
+        }
+        if symbol.symbol_kind != SymbolKind::Variable {
+            continue;
+        }
+        if is_loop_init {
+            semantic_data.error_token = symbol.token.clone();
+            semantic_data.error_detail = format!("'{}' is declared but never used, a for loop's variable has to be read", symbol.name);
+            semantic_data.error_code = SemanticError::UnusedVariable;
+            break;
+        }
+        if semantic_data.unused_variable_mode == UnusedVariableMode::Error {
+            semantic_data.error_token = symbol.token.clone();
+            semantic_data.error_detail = format!("'{}' is declared but never used, Go won't compile it", symbol.name);
+            semantic_data.error_code = SemanticError::UnusedVariable;
+            break;
+        }
+        semantic_data.add_warning(&symbol.token, format!("'{}' is declared but never used", symbol.name));
+        semantic_data.unused_variables.push(symbol.token.clone());
+    }
+    semantic_data.symbol_table.exit_scope();
+}
+
+fn check_statement(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkStatement".to_string();
+
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Print | ASTNodeType::Println => {
+                let child_count:usize = node.children.len();
+                for i in 0..child_count {
+                    check_expression(semantic_data, &node.children[i]);
+                    if semantic_data.is_error() {
+                        return;
+                    }
+                }
+                semantic_types::check_print_types(semantic_data, node);
+            }
+            ASTNodeType::Declaration => check_declaration(semantic_data, node),
+            ASTNodeType::MultipleDeclaration => {
+                let declaration_count:usize = node.children.len();
+                for i in 0..declaration_count {
+                    check_type_node(semantic_data, node.children[i].left);
+                    if semantic_data.is_error() {
+                        return;
+                    }
+                }
+                //the value is checked first, it can't see the names it declares
+                check_optional_expression(semantic_data, node.right);
+                if semantic_data.is_error() {
+                    return;
+                }
+                semantic_types::check_multiple_declaration_type(semantic_data, node);
+                let declaration_count:usize = node.children.len();
+                for i in 0..declaration_count {
+                    if semantic_data.is_error() {
+                        return;
+                    }
+                    check_declaration(semantic_data, &node.children[i]);
+                }
+            }
+            ASTNodeType::Assignment => {
+                //x = 1 doesn't read x, x += 1 and x.field = 1 do
+                let is_plain_assignment:bool = (*node.token).token_type == TokenType::Equals;
+                if is_plain_assignment && node.left.is_null() == false && (*node.left).node_type == ASTNodeType::Identifier {
+                    check_identifier(semantic_data, &*node.left, false);
+                } else {
+                    check_optional_expression(semantic_data, node.left);
+                }
+                if semantic_data.is_error() {
+                    return;
+                }
+                check_optional_expression(semantic_data, node.right);
+                if semantic_data.is_error() {
+                    return;
+                }
+                semantic_types::check_assignment_type(semantic_data, node);
+                if semantic_data.is_error() {
+                    return;
+                }
+                if node.left.is_null() == false && node.right.is_null() == false {
+                    let target_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+                    semantic_constants::check_constant_fits(semantic_data, &*node.right, &target_type);
+                    if semantic_data.is_error() {
+                        return;
+                    }
+                }
+                semantic_const::check_assignment_const(semantic_data, node);
+            }
+            ASTNodeType::Return => {
+                check_optional_expression(semantic_data, node.left);
+                if semantic_data.is_error() {
+                    return;
+                }
+                semantic_types::check_return_type(semantic_data, node);
+                if semantic_data.is_error() == false && node.left.is_null() == false {
+                    let return_type:String = semantic_data.function_return_type.clone();
+                    semantic_constants::check_constant_fits(semantic_data, &*node.left, &return_type);
+                }
+            }
+            ASTNodeType::Spawn => {
+                check_optional_expression(semantic_data, node.left);
+                if semantic_data.is_error() == false {
+                    semantic_types::check_spawn_type(semantic_data, node);
+                }
+            }
+            ASTNodeType::FunctionCall => check_expression(semantic_data, node),
+            ASTNodeType::IfStatement => check_if(semantic_data, node),
+            ASTNodeType::WhileLoop => {
+                check_condition(semantic_data, node.left, &*node.token);
+                if semantic_data.is_error() {
+                    return;
+                }
+                check_block(semantic_data, node.right);
+            }
+            ASTNodeType::DoWhileLoop => {
+                //the condition is checked after the body's scope has ended
+                check_block(semantic_data, node.right);
+                if semantic_data.is_error() {
+                    return;
+                }
+                //reported as a while condition, do has no condition of its own
+                let mut while_token:Token = (*node.token).clone();
+                while_token.text = "while".to_string();
+                check_condition(semantic_data, node.left, &while_token);
+            }
+            ASTNodeType::ForLoop => check_for(semantic_data, node),
+            ASTNodeType::Label => {
+                //Go labels can't be keywords, and convert writes labels of its own
+                check_go_name(semantic_data, &(*node.token).clone());
+                if semantic_data.is_error() == false && node.left.is_null() == false {
+                    check_statement(semantic_data, &*node.left);
+                }
+            }
+            //break and continue name labels, convert checks those
+            _ => {}
+        }
+    }
+}
+
+fn check_declaration(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: Declaration
+    //Token:     variable name
+    //Left:      type node
+    //Right:     value, null if declared without one
+
+    //"i32 x = x;" can't see the x it declares
+    check_declaration_value(semantic_data, node);
+    if semantic_data.is_error() {
+        return;
+    }
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        let type_text:String = type_utils::type_node_to_string(node.left);
+        declare_symbol(semantic_data, &name_token, &type_text, SymbolKind::Variable, node.is_const);
+    }
+}
+
+/// A global's type and value, its name was declared with the functions and structs
+pub fn check_global_declaration(semantic_data:&mut SemanticData, node:&ASTNode) {
+    check_declaration_value(semantic_data, node);
+}
+
+fn check_declaration_value(semantic_data:&mut SemanticData, node:&ASTNode) {
+    //'retrun x;' reads as a declaration of x, so a plain type name is also compared with the keywords
+    let is_plain_type:bool = unsafe {
+        node.left.is_null() == false && (*node.left).node_type == ASTNodeType::VarType && (*node.left).token.is_null() == false
+    };
+    if is_plain_type {
+        unsafe {
+            check_type_name(semantic_data, &*(*node.left).token, true);
+        }
+    } else {
+        check_type_node(semantic_data, node.left);
+    }
+    if semantic_data.is_error() {
+        return;
+    }
+    check_optional_expression(semantic_data, node.right);
+    if semantic_data.is_error() {
+        return;
+    }
+    semantic_types::check_declaration_type(semantic_data, node);
+    if semantic_data.is_error() || node.right.is_null() {
+        return;
+    }
+    let type_text:String = type_utils::type_node_to_string(node.left);
+    unsafe {
+        semantic_constants::check_constant_fits(semantic_data, &*node.right, &type_text);
+    }
+}
+
+fn check_if(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: IfStatement
+    //Token:     if
+    //Left:      condition
+    //Middle:    IfBody
+    //Right:     Else, null without one
+
+    unsafe {
+        check_condition(semantic_data, node.left, &*node.token);
+        if semantic_data.is_error() {
+            return;
+        }
+        check_block(semantic_data, node.middle);
+        if semantic_data.is_error() || node.right.is_null() {
+            return;
+        }
+
+        //Node_type: Else
+        //Left:      IfStatement for else if, otherwise ElseBody
+        let else_node:&ASTNode = &*node.right;
+        if else_node.left.is_null() {
+            return;
+        }
+        if (*else_node.left).node_type == ASTNodeType::IfStatement {
+            check_if(semantic_data, &*else_node.left);
+            return;
+        }
+        check_block(semantic_data, else_node.left);
+    }
+}
+
+fn check_for(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: ForLoop
+    //Left:      ForCondition, its init, condition and step
+    //Right:     ForBody
+
+    //the init's declaration lives until the loop ends, the body gets a scope inside that
+    semantic_data.symbol_table.enter_scope();
+    unsafe {
+        if node.left.is_null() == false {
+            let condition_node:&ASTNode = &*node.left;
+            if condition_node.left.is_null() == false {
+                semantic_types::check_for_init_type(semantic_data, &*condition_node.left);
+                if semantic_data.is_error() == false {
+                    check_statement(semantic_data, &*condition_node.left);
+                }
+            }
+            if semantic_data.is_error() == false {
+                check_condition(semantic_data, condition_node.middle, &*node.token);
+            }
+            if semantic_data.is_error() == false && condition_node.right.is_null() == false {
+                check_statement(semantic_data, &*condition_node.right);
+            }
+        }
+        if semantic_data.is_error() == false {
+            check_block(semantic_data, node.right);
+        }
+    }
+    exit_scope(semantic_data, true);
+}
+
+// The condition of an if or a loop, keyword_token is where a condition that isn't a bool is reported
+fn check_condition(semantic_data:&mut SemanticData, condition_ptr:*mut ASTNode, keyword_token:&Token) {
+    if condition_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        check_expression(semantic_data, &*condition_ptr);
+        if semantic_data.is_error() {
+            return;
+        }
+        semantic_types::check_condition_type(semantic_data, &*condition_ptr, keyword_token, &keyword_token.text);
+    }
+}
+
+fn check_optional_expression(semantic_data:&mut SemanticData, node_ptr:*mut ASTNode) {
+    if node_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        check_expression(semantic_data, &*node_ptr);
+    }
+}
+
+fn check_expression(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkExpression".to_string();
+
+    if node.token.is_null() {
+        return;
+    }
+
+    unsafe {
+        match node.node_type {
+            ASTNodeType::Identifier => check_identifier(semantic_data, node, true),
+            ASTNodeType::FunctionCall => {
+                //Left:      the function being called, a name or a function value
+                //Middle:    TypeArguments, types rather than names
+                //Children:  arguments
+                check_optional_expression(semantic_data, node.left);
+                let argument_count:usize = node.children.len();
+                for i in 0..argument_count {
+                    if semantic_data.is_error() {
+                        return;
+                    }
+                    check_expression(semantic_data, &node.children[i]);
+                }
+            }
+            //the field name belongs to the struct, only the value is looked up
+            ASTNodeType::FieldAccess | ASTNodeType::Minus => check_optional_expression(semantic_data, node.left),
+            ASTNodeType::Cast => check_optional_expression(semantic_data, node.right),
+            ASTNodeType::ArrayAccess | ASTNodeType::Ternary => {
+                check_optional_expression(semantic_data, node.left);
+                if semantic_data.is_error() == false {
+                    check_optional_expression(semantic_data, node.middle);
+                }
+                if semantic_data.is_error() == false {
+                    check_optional_expression(semantic_data, node.right);
+                }
+            }
+            _ => {
+                if type_utils::is_binary_node(node) {
+                    check_expression(semantic_data, &*node.left);
+                    if semantic_data.is_error() == false {
+                        check_expression(semantic_data, &*node.right);
+                    }
+                }
+            }
+        }
+    }
+    if semantic_data.is_error() {
+        return;
+    }
+    //names first, so a type is only worked out once everything in it is declared
+    semantic_types::check_expression_type(semantic_data, node);
+    if semantic_data.is_error() == false {
+        semantic_constants::check_expression_constants(semantic_data, node);
+    }
+    if semantic_data.is_error() == false && node.node_type == ASTNodeType::FunctionCall {
+        semantic_const::check_call_const(semantic_data, node);
+    }
+}
+
+// is_read false for the target of a plain assignment, which doesn't count as using the name
+fn check_identifier(semantic_data:&mut SemanticData, node:&ASTNode, is_read:bool) {
+
+    semantic_data.error_function = "checkIdentifier".to_string();
+
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        let symbol:Symbol = semantic_data.symbol_table.get_symbol(&name_token.text);
+
+        if symbol.symbol_kind == SymbolKind::Struct || symbol.symbol_kind == SymbolKind::TypeParameter {
+            semantic_data.error_token = name_token.clone();
+            semantic_data.error_detail = format!("'{}' is a type, not a value", name_token.text);
+            semantic_data.error_code = SemanticError::UndeclaredName;
+            return;
+        }
+        if symbol.symbol_kind != SymbolKind::None {
+            if is_read {
+                semantic_data.symbol_table.mark_used(&name_token.text);
+            }
+            return;
+        }
+        //builtins are only used when nothing the program declares has the name
+        if builtins::is_builtin_function(&name_token.text) || builtins::is_builtin_constant(&name_token.text) {
+            return;
+        }
+
+        semantic_data.error_token = name_token.clone();
+        semantic_data.error_detail = format!("'{}' is not declared{}", name_token.text, get_name_suggestion(semantic_data, &name_token.text));
+        semantic_data.error_code = SemanticError::UndeclaredName;
+    }
+}
+
+// Values in scope, builtins and keywords, a misspelled println is as likely as a misspelled variable
+fn get_name_suggestion(semantic_data:&SemanticData, name:&str) -> String {
+    let mut candidates:Vec<String> = Vec::new();
+    let symbol_count:usize = semantic_data.symbol_table.symbols.len();
+    for i in 0..symbol_count {
+        let symbol:&Symbol = &semantic_data.symbol_table.symbols[i];
+        let is_value:bool = symbol.symbol_kind == SymbolKind::Variable
+            || symbol.symbol_kind == SymbolKind::Parameter
+            || symbol.symbol_kind == SymbolKind::Function;
+        if is_value {
+            candidates.push(symbol.name.clone());
+        }
+    }
+    for i in 0..builtins::FUNCTION_NAMES.len() {
+        candidates.push(builtins::FUNCTION_NAMES[i].to_string());
+    }
+    candidates.push(builtins::PI.to_string());
+    candidates.push(builtins::E.to_string());
+    for i in 0..parse_utils::KEYWORDS.len() {
+        candidates.push(parse_utils::KEYWORDS[i].to_string());
+    }
+    return token_utils::get_suggestion_text(name, &candidates);
+}
+
+/// Every name a type is written with has to be a builtin type, a struct or a type parameter in scope
+pub fn check_type_node(semantic_data:&mut SemanticData, type_node_ptr:*mut ASTNode) {
+    if type_node_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        let type_node:&ASTNode = &*type_node_ptr;
+        match type_node.node_type {
+            ASTNodeType::Pointer | ASTNodeType::Array | ASTNodeType::Channel => check_type_node(semantic_data, type_node.left),
+            ASTNodeType::FunctionType => {
+                //Middle:    Parameters, each a Parameter or a bare type
+                //Right:     return type
+                if type_node.middle.is_null() == false {
+                    let parameters:&ASTNode = &*type_node.middle;
+                    let parameter_count:usize = parameters.children.len();
+                    for i in 0..parameter_count {
+                        let parameter:&ASTNode = &parameters.children[i];
+                        if parameter.node_type == ASTNodeType::Parameter {
+                            check_type_node(semantic_data, parameter.left);
+                        } else if parameter.token.is_null() == false {
+                            check_type_name(semantic_data, &*parameter.token, false);
+                        }
+                        if semantic_data.is_error() {
+                            return;
+                        }
+                    }
+                }
+                check_type_node(semantic_data, type_node.right);
+            }
+            _ => {
+                if type_node.token.is_null() == false {
+                    check_type_name(semantic_data, &*type_node.token, false);
+                }
+            }
+        }
+    }
+}
+
+fn check_type_name(semantic_data:&mut SemanticData, type_token:&Token, is_followed_by_name:bool) {
+
+    semantic_data.error_function = "checkTypeName".to_string();
+
+    if token_utils::is_var_type(type_token.token_type) || type_token.token_type == TokenType::Void {
+        return;
+    }
+    if type_utils::is_sync_type_text(&type_token.text) {
+        return;
+    }
+    let symbol:Symbol = semantic_data.symbol_table.get_symbol(&type_token.text);
+    if symbol.symbol_kind == SymbolKind::Struct || symbol.symbol_kind == SymbolKind::TypeParameter {
+        return;
+    }
+
+    let mut candidates:Vec<String> = Vec::new();
+    for i in 0..parse_utils::TYPE_NAMES.len() {
+        candidates.push(parse_utils::TYPE_NAMES[i].to_string());
+    }
+    candidates.push(type_utils::WAIT_GROUP_TYPE.to_string());
+    candidates.push(type_utils::MUTEX_TYPE.to_string());
+    let symbol_count:usize = semantic_data.symbol_table.symbols.len();
+    for i in 0..symbol_count {
+        let type_symbol:&Symbol = &semantic_data.symbol_table.symbols[i];
+        if type_symbol.symbol_kind == SymbolKind::Struct || type_symbol.symbol_kind == SymbolKind::TypeParameter {
+            candidates.push(type_symbol.name.clone());
+        }
+    }
+    //the types come first, a tie goes to them
+    if is_followed_by_name {
+        for i in 0..parse_utils::KEYWORDS.len() {
+            candidates.push(parse_utils::KEYWORDS[i].to_string());
+        }
+    }
+
+    semantic_data.error_token = type_token.clone();
+    semantic_data.error_detail = format!("unknown type '{}'{}", type_token.text, token_utils::get_suggestion_text(&type_token.text, &candidates));
+    semantic_data.error_code = SemanticError::UndeclaredName;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::errors::SemanticError;
+    use crate::test_utils;
+
+    #[test]
+    fn go_keyword_as_a_name() {
+        let code:String = "fn void main() {\n    i32 range = 1;\n    println(range);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::ReservedName);
+    }
+
+    #[test]
+    fn go_package_as_a_name() {
+        let code:String = "fn void main() {\n    string fmt = \"a\";\n    println(fmt);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::ReservedName);
+    }
+
+    #[test]
+    fn generated_prefix_as_a_name() {
+        let code:String = "fn void main() {\n    i32 __x = 1;\n    println(__x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::ReservedName);
+    }
+
+    #[test]
+    fn function_named_like_the_wrapped_main() {
+        let code:String = "fn void __main() {\n}\nfn i32 main() {\n    __main();\n    return 0;\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::ReservedName);
+    }
+
+    #[test]
+    fn go_keyword_as_a_field() {
+        let code:String = "struct point {\n    i32 type;\n}\nfn void main() {\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::ReservedName);
+    }
+
+    #[test]
+    fn misspelled_keyword_suggests_the_keyword() {
+        let code:String = "fn i32 f(i32 x) {\n    retrun x;\n}\nfn void main() {\n    println(f(1));\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::UndeclaredName);
+        assert_eq!(test_utils::get_semantic_error_detail(&code), "unknown type 'retrun', did you mean 'return'?");
+    }
+}
@@ -0,0 +1,628 @@
+use crate::core::builtins;
+use crate::core::enums;
+use crate::core::errors;
+use crate::core::structs;
+use crate::core::token_utils;
+use crate::core::type_utils;
+use crate::semantic::semantic_generics;
+use enums::ASTNodeType;
+use enums::SymbolKind;
+use enums::TokenType;
+use errors::SemanticError;
+use structs::ASTNode;
+use structs::SemanticData;
+use structs::Symbol;
+use structs::Token;
+
+// Types are never inferred, a value has to be exactly the type it is given to.
+// An empty type means it couldn't be worked out, the check that caused that has already reported it.
+
+fn set_type_error(semantic_data:&mut SemanticData, token:&Token, detail:String) {
+    semantic_data.error_token = token.clone();
+    semantic_data.error_detail = detail;
+    semantic_data.error_code = SemanticError::TypeMismatch;
+}
+
+fn set_argument_count_error(semantic_data:&mut SemanticData, token:&Token, detail:String) {
+    semantic_data.error_token = token.clone();
+    semantic_data.error_detail = detail;
+    semantic_data.error_code = SemanticError::InvalidArgumentCount;
+}
+
+fn set_return_error(semantic_data:&mut SemanticData, token:&Token, detail:String) {
+    semantic_data.error_token = token.clone();
+    semantic_data.error_detail = detail;
+    semantic_data.error_code = SemanticError::InvalidReturnType;
+}
+
+/// Checks one expression node, its children have been checked already
+pub fn check_expression_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkExpressionType".to_string();
+
+    unsafe {
+        match node.node_type {
+            ASTNodeType::FunctionCall => check_call_type(semantic_data, node),
+            ASTNodeType::FieldAccess => check_field_access_type(semantic_data, node),
+            ASTNodeType::ArrayAccess => check_index_type(semantic_data, node),
+            ASTNodeType::Cast => check_cast_type(semantic_data, node),
+            ASTNodeType::Ternary => check_ternary_type(semantic_data, node),
+            ASTNodeType::Minus => {
+                let value_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+                if value_type.is_empty() == false && type_utils::is_numeric_type_text(&value_type) == false {
+                    let minus_token:Token = (*node.token).clone();
+                    set_type_error(semantic_data, &minus_token, format!("'-' needs a number, found '{}'", value_type));
+                }
+            }
+            _ => {
+                if type_utils::is_binary_node(node) {
+                    check_binary_type(semantic_data, node);
+                }
+            }
+        }
+    }
+}
+
+fn check_binary_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Token: operator
+    //Left:  left side
+    //Right: right side
+
+    unsafe {
+        let operator_token:Token = (*node.token).clone();
+        let left_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+        let right_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+        if left_type.is_empty() || right_type.is_empty() {
+            return;
+        }
+
+        //types can only be mixed through a cast
+        if type_utils::operand_types_match(&left_type, &right_type, &semantic_data.symbol_table) == false {
+            set_type_error(semantic_data, &operator_token, format!(
+                "cannot mix '{}' and '{}' with '{}', use a cast",
+                left_type, right_type, operator_token.text
+            ));
+            return;
+        }
+        let operand_type:String = type_utils::get_operand_type(&left_type, &right_type);
+        if type_utils::is_operator_valid_for_type(operator_token.token_type, &operand_type) == false {
+            set_type_error(semantic_data, &operator_token, format!("'{}' can't be used on '{}'", operator_token.text, operand_type));
+        }
+    }
+}
+
+fn check_call_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: FunctionCall
+    //Token:     function name
+    //Left:      the function being called, a name or a function value
+    //Children:  arguments
+
+    unsafe {
+        let call_token:Token = (*node.token).clone();
+
+        if builtins::is_builtin_call(node, &semantic_data.symbol_table) {
+            let argument_types:Vec<String> = builtins::get_argument_types(node, &semantic_data.symbol_table);
+            let argument_error:String = builtins::check_builtin_arguments(&call_token.text, &argument_types);
+            if argument_error.is_empty() {
+                return;
+            }
+            if builtins::is_argument_count_valid(&call_token.text, argument_types.len()) == false {
+                set_argument_count_error(semantic_data, &call_token, format!("{}, '{}' is a builtin", argument_error, call_token.text));
+                return;
+            }
+            set_type_error(semantic_data, &call_token, argument_error);
+            return;
+        }
+
+        if node.left.is_null() {
+            return;
+        }
+        semantic_generics::check_type_arguments(semantic_data, node);
+        if semantic_data.is_error() {
+            return;
+        }
+        let function_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+        if function_type.is_empty() == false && type_utils::is_function_type_text(&function_type) == false {
+            set_type_error(semantic_data, &call_token, format!("cannot call '{}', it has type '{}'", call_token.text, function_type));
+            return;
+        }
+        check_call_arguments(semantic_data, node);
+    }
+}
+
+// Arguments against the parameters of the function being called, pointing back at where it is declared
+fn check_call_arguments(semantic_data:&mut SemanticData, node:&ASTNode) {
+    unsafe {
+        let call_token:Token = (*node.token).clone();
+        let function_type:String = type_utils::get_call_function_type(node, &semantic_data.symbol_table);
+        if type_utils::is_function_type_text(&function_type) == false {
+            return;
+        }
+        let parameter_types:Vec<String> = type_utils::get_function_parameter_types(&function_type);
+
+        //a function declared by name has parameter names, a function value only has its type
+        let mut parameter_names:Vec<String> = Vec::new();
+        let mut declaration_token:Token = structs::empty_token();
+        let callee:&ASTNode = &*node.left;
+        if callee.node_type == ASTNodeType::Identifier {
+            let symbol:Symbol = semantic_data.symbol_table.get_symbol(&(*callee.token).text);
+            declaration_token = symbol.token.clone();
+            let declaration_ptr:*const ASTNode = semantic_data.get_function_declaration(&symbol.name);
+            if symbol.symbol_kind == SymbolKind::Function && declaration_ptr.is_null() == false && (*declaration_ptr).middle.is_null() == false {
+                let parameters:&ASTNode = &*(*declaration_ptr).middle;
+                let parameter_count:usize = parameters.children.len();
+                for i in 0..parameter_count {
+                    parameter_names.push((*parameters.children[i].token).text.clone());
+                }
+            }
+        }
+        let mut declared_at:String = String::new();
+        if declaration_token.text.is_empty() == false {
+            declared_at = format!(", '{}' is declared at {}", call_token.text, semantic_data.get_source_location(&declaration_token));
+        }
+
+        let argument_count:usize = node.children.len();
+        if argument_count != parameter_types.len() {
+            set_argument_count_error(semantic_data, &call_token, format!(
+                "'{}' takes {} argument(s) '{}', found {}{}",
+                call_token.text, parameter_types.len(), function_type, argument_count, declared_at
+            ));
+            return;
+        }
+
+        for i in 0..argument_count {
+            let argument:&ASTNode = &node.children[i];
+            let argument_type:String = type_utils::get_expression_type(argument, &semantic_data.symbol_table);
+            if argument_type.is_empty() || type_utils::types_match(&parameter_types[i], &argument_type) {
+                continue;
+            }
+            let mut parameter_text:String = String::new();
+            if i < parameter_names.len() {
+                parameter_text = format!(" for parameter '{}'", parameter_names[i]);
+            }
+            let argument_token:Token = (*argument.token).clone();
+            set_type_error(semantic_data, &argument_token, format!(
+                "argument {} of '{}' must be '{}'{}, found '{}'{}",
+                i + 1, call_token.text, parameter_types[i], parameter_text, argument_type, declared_at
+            ));
+            return;
+        }
+    }
+}
+
+fn check_field_access_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: FieldAccess
+    //Token:     field name
+    //Left:      struct value
+
+    unsafe {
+        let field_token:Token = (*node.token).clone();
+        let value_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+        if value_type.is_empty() {
+            return;
+        }
+        let struct_name:String = type_utils::get_struct_name(&value_type);
+        let struct_symbol:Symbol = semantic_data.symbol_table.get_symbol(&struct_name);
+        if struct_symbol.symbol_kind != SymbolKind::Struct {
+            set_type_error(semantic_data, &field_token, format!("'{}' has no fields, only structs do", value_type));
+            return;
+        }
+        let field_name:String = type_utils::get_field_symbol_name(&struct_name, &field_token.text);
+        let field:Symbol = semantic_data.symbol_table.get_symbol(&field_name);
+        if field.symbol_kind != SymbolKind::Field {
+            //fields are kept as "Struct.field"
+            let field_prefix:String = type_utils::get_field_symbol_name(&struct_name, "");
+            let mut field_names:Vec<String> = Vec::new();
+            let symbol_count:usize = semantic_data.symbol_table.symbols.len();
+            for i in 0..symbol_count {
+                let symbol:&Symbol = &semantic_data.symbol_table.symbols[i];
+                if symbol.symbol_kind == SymbolKind::Field && symbol.name.starts_with(&field_prefix) {
+                    field_names.push(symbol.name[field_prefix.len()..].to_string());
+                }
+            }
+            semantic_data.error_token = field_token.clone();
+            semantic_data.error_detail = format!(
+                "struct '{}' has no field '{}'{}",
+                struct_name, field_token.text, token_utils::get_suggestion_text(&field_token.text, &field_names)
+            );
+            semantic_data.error_code = SemanticError::UndeclaredName;
+        }
+    }
+}
+
+fn check_index_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: ArrayAccess
+    //Token:     '['
+    //Left:      string or array being indexed
+    //Right:     index
+
+    unsafe {
+        let bracket_token:Token = (*node.token).clone();
+        let value_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+        if value_type.is_empty() == false && type_utils::get_index_result_type(&value_type).is_empty() {
+            set_type_error(semantic_data, &bracket_token, format!("cannot index '{}', only strings and arrays can be", value_type));
+            return;
+        }
+        let index_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+        if index_type.is_empty() == false && type_utils::is_integer_type_text(&index_type) == false {
+            set_type_error(semantic_data, &bracket_token, format!("index must be an integer, found '{}'", index_type));
+        }
+    }
+}
+
+fn check_cast_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: Cast
+    //Token:     type token
+    //Left:      type node
+    //Right:     value being cast
+
+    unsafe {
+        let type_token:Token = (*node.token).clone();
+        let target_type:String = type_utils::type_node_to_string(node.left);
+        let value_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+
+        if type_utils::is_castable_type_text(&target_type) == false {
+            set_type_error(semantic_data, &type_token, format!("cannot cast to '{}', only number and char types can be cast", target_type));
+            return;
+        }
+        if value_type.is_empty() == false && type_utils::is_castable_type_text(&value_type) == false {
+            set_type_error(semantic_data, &type_token, format!(
+                "cannot cast '{}' to '{}', only number and char types can be cast",
+                value_type, target_type
+            ));
+        }
+    }
+}
+
+fn check_ternary_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    //Node_type: Ternary
+    //Token:     '?'
+    //Left:      condition
+    //Middle:    value if true
+    //Right:     value if false
+
+    unsafe {
+        let question_token:Token = (*node.token).clone();
+        check_condition_type(semantic_data, &*node.left, &question_token, "ternary");
+        if semantic_data.is_error() {
+            return;
+        }
+        let true_type:String = type_utils::get_expression_type(&*node.middle, &semantic_data.symbol_table);
+        let false_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+        //convert holds the chosen value in a variable, which needs a type
+        if true_type.is_empty() || false_type.is_empty() {
+            set_type_error(semantic_data, &question_token, "can't work out the type of both ternary values".to_string());
+            return;
+        }
+        if type_utils::types_match(&true_type, &false_type) == false {
+            set_type_error(semantic_data, &question_token, format!(
+                "both ternary values must have the same type, found '{}' and '{}'",
+                true_type, false_type
+            ));
+        }
+    }
+}
+
+/// if, while, for and ternary conditions, nothing turns into a bool by itself
+pub fn check_condition_type(semantic_data:&mut SemanticData, condition_node:&ASTNode, keyword_token:&Token, keyword:&str) {
+
+    semantic_data.error_function = "checkConditionType".to_string();
+
+    let condition_type:String = type_utils::get_expression_type(condition_node, &semantic_data.symbol_table);
+    if condition_type.is_empty() == false && condition_type != type_utils::BOOL_TYPE {
+        set_type_error(semantic_data, keyword_token, format!("{} condition must be 'bool', found '{}'", keyword, condition_type));
+    }
+}
+
+/// The value of "i32 x = value;" against the declared type
+pub fn check_declaration_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkDeclarationType".to_string();
+
+    //Node_type: Declaration
+    //Token:     variable name
+    //Left:      type node
+    //Right:     value, null if declared without one
+
+    if node.right.is_null() {
+        return;
+    }
+    unsafe {
+        let name_token:Token = (*node.token).clone();
+        let type_text:String = type_utils::type_node_to_string(node.left);
+        let value_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+
+        if type_utils::is_tuple_type_text(&value_type) {
+            set_type_error(semantic_data, &name_token, format!(
+                "the value gives {} values '{}', declare a variable for each, e.g. {} {}, bool ok = ...",
+                type_utils::get_tuple_types(&value_type).len(), value_type, type_text, name_token.text
+            ));
+            return;
+        }
+        if value_type.is_empty() == false && type_utils::types_match(&type_text, &value_type) == false {
+            set_type_error(semantic_data, &name_token, format!(
+                "cannot assign '{}' to '{}' declared as '{}'",
+                value_type, name_token.text, type_text
+            ));
+        }
+    }
+}
+
+/// "i32 value, bool ok = read_i32();", one result per declaration
+pub fn check_multiple_declaration_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkMultipleDeclarationType".to_string();
+
+    //Node_type: MultipleDeclaration
+    //Token:     '='
+    //Children:  Declaration nodes without values
+    //Right:     value giving one result per declaration
+
+    if node.right.is_null() {
+        return;
+    }
+    unsafe {
+        let equals_token:Token = (*node.token).clone();
+        let value_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+        if value_type.is_empty() {
+            return;
+        }
+        let value_types:Vec<String> = type_utils::get_tuple_types(&value_type);
+        let declaration_count:usize = node.children.len();
+        if value_types.len() != declaration_count {
+            set_type_error(semantic_data, &equals_token, format!(
+                "{} variables declared but the value gives '{}'",
+                declaration_count, value_type
+            ));
+            return;
+        }
+        for i in 0..declaration_count {
+            let declaration:&ASTNode = &node.children[i];
+            let name_token:Token = (*declaration.token).clone();
+            let type_text:String = type_utils::type_node_to_string(declaration.left);
+            if type_utils::types_match(&type_text, &value_types[i]) == false {
+                set_type_error(semantic_data, &name_token, format!(
+                    "cannot assign '{}' to '{}' declared as '{}'",
+                    value_types[i], name_token.text, type_text
+                ));
+                return;
+            }
+        }
+    }
+}
+
+/// x = value, x += value and x++, the target decides the type
+pub fn check_assignment_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkAssignmentType".to_string();
+
+    //Node_type: Assignment
+    //Token:     assignment operator, ++ or --
+    //Left:      variable being assigned to
+    //Right:     value, null for ++ and --
+
+    if node.left.is_null() {
+        return;
+    }
+    unsafe {
+        let operator_token:Token = (*node.token).clone();
+        let target_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+        if target_type.is_empty() {
+            return;
+        }
+
+        if node.right.is_null() {
+            if type_utils::is_numeric_type_text(&target_type) == false {
+                set_type_error(semantic_data, &operator_token, format!("'{}' needs a number, found '{}'", operator_token.text, target_type));
+            }
+            return;
+        }
+
+        let value_type:String = type_utils::get_expression_type(&*node.right, &semantic_data.symbol_table);
+        if value_type.is_empty() == false && type_utils::types_match(&target_type, &value_type) == false {
+            set_type_error(semantic_data, &operator_token, format!("cannot assign '{}' to '{}'", value_type, target_type));
+            return;
+        }
+        let compound_operator:i32 = token_utils::get_compound_operator(operator_token.token_type);
+        if compound_operator != TokenType::Equals && type_utils::is_operator_valid_for_type(compound_operator, &target_type) == false {
+            set_type_error(semantic_data, &operator_token, format!("'{}' can't be used on '{}'", operator_token.text, target_type));
+            return;
+        }
+
+        //Go strings can't be changed in place
+        let target_node:&ASTNode = &*node.left;
+        if target_node.node_type == ASTNodeType::ArrayAccess {
+            let indexed_type:String = type_utils::get_expression_type(&*target_node.left, &semantic_data.symbol_table);
+            if indexed_type == type_utils::STRING_TYPE {
+                set_type_error(semantic_data, &operator_token, "cannot assign to a character of a string, strings can't be changed".to_string());
+            }
+        }
+    }
+}
+
+/// The returned value against the function's return type
+pub fn check_return_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkReturnType".to_string();
+
+    //Node_type: Return
+    //Token:     return token
+    //Left:      returned value, null for 'return;'
+
+    unsafe {
+        let return_token:Token = (*node.token).clone();
+        let return_type:String = semantic_data.function_return_type.clone();
+
+        if node.left.is_null() {
+            if return_type != type_utils::VOID_TYPE {
+                set_return_error(semantic_data, &return_token, format!("missing return value, function returns '{}'", return_type));
+            }
+            return;
+        }
+        if return_type == type_utils::VOID_TYPE {
+            set_return_error(semantic_data, &return_token, "cannot return a value from a void function".to_string());
+            return;
+        }
+        let value_type:String = type_utils::get_expression_type(&*node.left, &semantic_data.symbol_table);
+        if value_type.is_empty() == false && type_utils::types_match(&return_type, &value_type) == false {
+            set_return_error(semantic_data, &return_token, format!(
+                "cannot return '{}' from a function returning '{}'",
+                value_type, return_type
+            ));
+        }
+    }
+}
+
+/// A call to a void function gives nothing to print
+pub fn check_print_types(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkPrintTypes".to_string();
+
+    let child_count:usize = node.children.len();
+    for i in 0..child_count {
+        let child:&ASTNode = &node.children[i];
+        let child_type:String = type_utils::get_expression_type(child, &semantic_data.symbol_table);
+        if child_type == type_utils::VOID_TYPE {
+            unsafe {
+                let child_token:Token = (*child.token).clone();
+                set_type_error(semantic_data, &child_token, format!("'{}' gives no value to print", child_token.text));
+            }
+            return;
+        }
+    }
+}
+
+/// Builtins like send are statements in Go, not calls a goroutine can run
+pub fn check_spawn_type(semantic_data:&mut SemanticData, node:&ASTNode) {
+
+    semantic_data.error_function = "checkSpawnType".to_string();
+
+    //Node_type: Spawn
+    //Token:     spawn
+    //Left:      FunctionCall run on its own goroutine
+
+    if node.left.is_null() {
+        return;
+    }
+    unsafe {
+        let call_node:&ASTNode = &*node.left;
+        if builtins::is_builtin_call(call_node, &semantic_data.symbol_table) {
+            let spawn_token:Token = (*node.token).clone();
+            set_type_error(semantic_data, &spawn_token, format!(
+                "cannot spawn the builtin '{}', spawn a function that calls it",
+                (*call_node.token).text
+            ));
+        }
+    }
+}
+
+/// A zero valued struct would hold a nil WaitGroup or Mutex
+pub fn check_field_type(semantic_data:&mut SemanticData, field:&ASTNode) {
+
+    semantic_data.error_function = "checkFieldType".to_string();
+
+    //Node_type: StructField
+    //Token:     field name
+    //Left:      type node
+
+    if field.token.is_null() {
+        return;
+    }
+    let field_type:String = type_utils::type_node_to_string(field.left);
+    if type_utils::is_sync_type_text(&field_type) {
+        unsafe {
+            let field_token:Token = (*field.token).clone();
+            set_type_error(semantic_data, &field_token, format!(
+                "a struct can't hold a '{}', declare it as a variable and pass it alongside",
+                field_type
+            ));
+        }
+    }
+}
+
+/// Go's for declares with :=, which has nothing to give a variable without a value
+pub fn check_for_init_type(semantic_data:&mut SemanticData, init_node:&ASTNode) {
+
+    semantic_data.error_function = "checkForInitType".to_string();
+
+    if init_node.node_type != ASTNodeType::Declaration || init_node.right.is_null() == false {
+        return;
+    }
+    unsafe {
+        let name_token:Token = (*init_node.token).clone();
+        set_type_error(semantic_data, &name_token, "a for loop's declaration needs a value, e.g. i32 i = 0".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::errors::SemanticError;
+    use crate::test_utils;
+
+    const SUB:&str = "fn <T> T sub(T a) {\n    return a - a;\n}\n";
+    const LESS_THAN:&str = "fn <T> bool lt(T a, T b) {\n    return a < b;\n}\n";
+
+    #[test]
+    fn argument_must_match_parameter() {
+        let code:String = "fn void f(i32 a) {\n    println(a);\n}\nfn void main() {\n    f(\"a\");\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::TypeMismatch);
+    }
+
+    #[test]
+    fn type_argument_must_be_a_number_for_minus() {
+        let code:String = format!("{}fn void main() {{\n    string s = sub<string>(\"a\");\n    println(s);\n}}\n", SUB);
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::InvalidTypeArguments);
+        assert_eq!(
+            test_utils::get_semantic_error_detail(&code),
+            "type argument 1 of 'sub' must be a number type for 'T', found 'string'"
+        );
+    }
+
+    #[test]
+    fn type_argument_must_be_ordered_for_less_than() {
+        let code:String = format!("{}fn void main() {{\n    bool b = lt<bool>(true, false);\n    println(b);\n}}\n", LESS_THAN);
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::InvalidTypeArguments);
+    }
+
+    #[test]
+    fn type_argument_needs_what_the_callee_needs() {
+        let code:String = format!(
+            "{}fn <U> bool wrap(U a) {{\n    return lt<U>(a, a);\n}}\nfn void main() {{\n    bool b = wrap<bool>(true);\n    println(b);\n}}\n",
+            LESS_THAN
+        );
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::InvalidTypeArguments);
+    }
+
+    #[test]
+    fn type_arguments_that_fit() {
+        let code:String = format!(
+            "{}{}fn void main() {{\n    i32 x = sub<i32>(3);\n    bool b = lt<string>(\"a\", \"b\");\n    println(x);\n    println(b);\n}}\n",
+            SUB, LESS_THAN
+        );
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::None);
+    }
+
+    #[test]
+    fn generic_call_needs_type_arguments() {
+        let code:String = format!("{}fn void main() {{\n    i32 x = sub(3);\n    println(x);\n}}\n", SUB);
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::InvalidTypeArguments);
+    }
+
+    #[test]
+    fn plain_call_takes_no_type_arguments() {
+        let code:String = "fn i32 one() {\n    return 1;\n}\nfn void main() {\n    i32 x = one<i32>();\n    println(x);\n}\n".to_string();
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::InvalidTypeArguments);
+    }
+
+    #[test]
+    fn generic_call_needs_one_type_argument_per_type_parameter() {
+        let code:String = format!("{}fn void main() {{\n    i32 x = sub<i32, i32>(3);\n    println(x);\n}}\n", SUB);
+        assert_eq!(test_utils::get_semantic_error(&code), SemanticError::InvalidTypeArguments);
+    }
+}
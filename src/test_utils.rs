@@ -0,0 +1,49 @@
+use crate::convert::go_convert;
+use crate::core::errors;
+use crate::core::structs;
+use crate::format::ast_modules;
+use crate::semantic::semantic_analysis;
+use errors::ConvertError;
+use errors::SemanticError;
+use structs::CompileOptions;
+use structs::ModuleData;
+use structs::SemanticData;
+use structs::SemanticResult;
+
+// Small programs run through the passes main runs, for the tests next to each pass
+
+fn load_code(code:&str) -> ModuleData {
+    let mut module_data:ModuleData = ModuleData::new();
+    ast_modules::load_program_from_code(&mut module_data, "test", &code.to_string());
+    assert!(module_data.is_error() == false, "the test code didn't parse:\n{}", code);
+    return module_data;
+}
+
+/// The SemanticError the code gives, SemanticError::None when it passes
+pub fn get_semantic_error(code:&str) -> i32 {
+    let module_data:ModuleData = load_code(code);
+    let mut semantic_data:SemanticData = semantic_analysis::create_semantic_data(&module_data.ast_nodes, &module_data.source_files, &CompileOptions::new());
+    semantic_analysis::check_program(&mut semantic_data);
+    return semantic_data.error_code;
+}
+
+/// What the semantic error says, empty when the code passes
+pub fn get_semantic_error_detail(code:&str) -> String {
+    let module_data:ModuleData = load_code(code);
+    let mut semantic_data:SemanticData = semantic_analysis::create_semantic_data(&module_data.ast_nodes, &module_data.source_files, &CompileOptions::new());
+    semantic_analysis::check_program(&mut semantic_data);
+    return semantic_data.error_detail;
+}
+
+/// The Go the code is written as, the code has to pass every check
+pub fn convert_code(code:&str) -> String {
+    let module_data:ModuleData = load_code(code);
+    let mut semantic_error:i32 = SemanticError::None;
+    let semantic_result:SemanticResult = semantic_analysis::analyse(&module_data.ast_nodes, &module_data.source_files, &CompileOptions::new(), &mut semantic_error);
+    assert_eq!(semantic_error, SemanticError::None, "the test code didn't pass the checks:\n{}", code);
+
+    let mut convert_error:i32 = ConvertError::None;
+    let go_code:String = go_convert::convert(&module_data.ast_nodes, &module_data.source_files, &semantic_result, &mut convert_error);
+    assert_eq!(convert_error, ConvertError::None, "the test code didn't convert:\n{}", code);
+    return go_code;
+}